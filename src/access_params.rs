@@ -1,3 +1,5 @@
+use crate::{suggestion, vis_param::VisParam};
+
 #[derive(Copy, Clone, Default)]
 pub enum GetRefType {
     #[default]
@@ -10,10 +12,30 @@ pub enum GetRefType {
 pub struct AccessParams {
     pub get_name: syn::Ident,
     pub get_mut_name: syn::Ident,
+    /// `Some` if the `set` param is given, the name of the setter is `set_<name>`
+    pub set_name: Option<syn::Ident>,
     pub get_ref_type: GetRefType,
+    /// `None` means the visibility given in the arguments of the macro
+    pub get_vis: Option<VisParam>,
+    /// `None` means the visibility given in the arguments of the macro
+    pub mut_vis: Option<VisParam>,
+    /// `None` means the visibility given in the arguments of the macro
+    pub set_vis: Option<VisParam>,
 }
 
 impl AccessParams {
+    const PARAMS: &'static [&'static str] = &[
+        "get_ref",
+        "get_copy",
+        "get_clone",
+        "name",
+        "vis",
+        "get_vis",
+        "mut_vis",
+        "set",
+        "set_vis",
+    ];
+
     pub fn parse(
         input: syn::parse::ParseStream,
         default_name: Option<syn::Ident>,
@@ -42,8 +64,36 @@ impl AccessParams {
             Ok(())
         }
 
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            new: VisParam,
+            param_name: &str,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("{} already specified", param_name),
+                ));
+            }
+            *existing = Some(new);
+            Ok(())
+        }
+
+        fn set_setter(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "set already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
         let mut name = None;
         let mut get_ref_type = None;
+        let mut get_vis = None;
+        let mut mut_vis = None;
+        let mut setter = false;
+        let mut setter_vis = None;
 
         if input.peek(syn::token::Paren) {
             let content;
@@ -67,12 +117,35 @@ impl AccessParams {
                         let name_lit: syn::LitStr = content.parse()?;
                         set_name(&mut name, name_lit, ident.span())?;
                     }
+                    "vis" => {
+                        let vis = VisParam::parse_value(&content)?;
+                        set_vis(&mut get_vis, vis.clone(), "get_vis", ident.span())?;
+                        set_vis(&mut mut_vis, vis.clone(), "mut_vis", ident.span())?;
+                        set_vis(&mut setter_vis, vis, "set_vis", ident.span())?;
+                    }
+                    "get_vis" => {
+                        let vis = VisParam::parse_value(&content)?;
+                        set_vis(&mut get_vis, vis, "get_vis", ident.span())?;
+                    }
+                    "mut_vis" => {
+                        let vis = VisParam::parse_value(&content)?;
+                        set_vis(&mut mut_vis, vis, "mut_vis", ident.span())?;
+                    }
+                    "set" => {
+                        set_setter(&mut setter, ident.span())?;
+                    }
+                    "set_vis" => {
+                        let vis = VisParam::parse_value(&content)?;
+                        set_vis(&mut setter_vis, vis, "set_vis", ident.span())?;
+                    }
                     other => {
                         return Err(syn::Error::new(
                             ident.span(),
                             format!(
-                                "expected one of `get_ref`, `get_copy`, `get_clone`, or `name = <..>`, found `{}`",
-                                other
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
                             ),
                         ));
                     }
@@ -95,12 +168,18 @@ impl AccessParams {
                 )
             })?,
         };
-        let get_mut_name = syn::Ident::new(&format!("{}_mut", get_name), get_name.span());
+        let get_mut_name = quote::format_ident!("{}_mut", get_name, span = get_name.span());
+        let set_name =
+            setter.then(|| quote::format_ident!("set_{}", get_name, span = get_name.span()));
 
         Ok(AccessParams {
             get_name,
             get_mut_name,
+            set_name,
             get_ref_type: get_ref_type.unwrap_or_default(),
+            get_vis,
+            mut_vis,
+            set_vis: setter_vis,
         })
     }
 }
@@ -1,30 +1,115 @@
+use syn::ext::IdentExt;
+
+use crate::{suggestion, vis_param::VisParam};
+
+#[derive(Clone)]
 pub struct Args {
     debug: bool,
+    vis: VisParam,
+    crate_path: syn::Path,
 }
 
 impl Args {
+    const ARGS: &'static [&'static str] = &["debug", "vis", "crate"];
+
     pub fn debug(&self) -> bool {
         self.debug
     }
+
+    /// default visibility of the generated methods
+    pub fn vis(&self) -> &VisParam {
+        &self.vis
+    }
+
+    /// path of the `attrimpl` crate (e.g., `::my_reexport::attrimpl`), the generated code does not
+    /// refer to the crate yet, only to `core` and `std`
+    #[allow(dead_code)]
+    pub fn crate_path(&self) -> &syn::Path {
+        &self.crate_path
+    }
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            debug: false,
+            vis: VisParam::Explicit(syn::parse_quote! { pub }),
+            crate_path: syn::parse_quote! { ::attrimpl },
+        }
+    }
 }
 
 impl syn::parse::Parse for Args {
+    /// Parses the arguments in the form of `ident` or `ident = "value"`, separated by commas.
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let ident: Option<syn::Ident> = input.parse()?;
+        let mut args = Args::default();
+        let mut specified = Vec::new();
 
-        let debug = if let Some(ident) = ident {
-            if ident == "debug" {
-                true
+        while !input.is_empty() {
+            // `crate` is a keyword, so keywords have to be accepted as well
+            let ident = input.call(syn::Ident::parse_any)?;
+            let value = if input.peek(syn::Token![=]) {
+                input.parse::<syn::Token![=]>()?;
+                Some(input.parse::<syn::LitStr>()?)
             } else {
+                None
+            };
+
+            let name = ident.to_string();
+            if !Self::ARGS.contains(&name.as_str()) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "unknown argument `{}`, expected {}{}",
+                        name,
+                        suggestion::expected(Self::ARGS),
+                        suggestion::did_you_mean(&name, Self::ARGS),
+                    ),
+                ));
+            }
+            if specified.contains(&name) {
                 return Err(syn::Error::new(
                     ident.span(),
-                    format!("unknown argument: {}", ident),
+                    format!("`{}` already specified", name),
                 ));
             }
-        } else {
-            false
-        };
 
-        Ok(Args { debug })
+            match (name.as_str(), value) {
+                ("debug", None) => args.debug = true,
+                ("vis", Some(value)) => args.vis = VisParam::from_lit(&value)?,
+                ("crate", Some(value)) => {
+                    args.crate_path = value.parse().map_err(|_| {
+                        syn::Error::new(
+                            value.span(),
+                            format!(
+                                "expected a path (e.g., `::my_reexport::attrimpl`), found `{}`",
+                                value.value()
+                            ),
+                        )
+                    })?;
+                }
+                (_, Some(value)) => {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!("`{}` does not accept a value", name),
+                    ));
+                }
+                (_, None) => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("`{}` requires a value (e.g., `{} = \"...\"`)", name, name),
+                    ));
+                }
+            }
+            specified.push(name);
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<syn::Token![,]>()?;
+        }
+
+        Ok(args)
     }
 }
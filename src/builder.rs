@@ -0,0 +1,380 @@
+use quote::{format_ident, quote};
+
+use crate::{
+    args::Args, builder_params::BuilderParams, create_generic_idents, create_generics_for_impl,
+    syn_field::SynField,
+};
+
+pub fn builder_to_tokens(
+    args: &Args,
+    params: &BuilderParams,
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    if params.typestate {
+        typestate_builder_to_tokens(args, item_struct, fields, tokens);
+    } else {
+        runtime_builder_to_tokens(args, item_struct, fields, tokens);
+    }
+}
+
+fn is_skipped(field: &SynField) -> bool {
+    field.builder_params().is_some_and(|params| params.skip)
+}
+
+/// type of the parameter of the method of the field, and the expression that converts it
+fn method_param_tokens(field: &SynField) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let field_type = &field.field.ty;
+
+    if field.builder_params().is_some_and(|params| params.into) {
+        (
+            quote! { impl ::core::convert::Into<#field_type> },
+            quote! { ::core::convert::Into::into(value) },
+        )
+    } else {
+        (quote! { #field_type }, quote! { value })
+    }
+}
+
+/// Generates `<Struct>Builder` with a method for every field and `build()`, the error type
+/// `<Struct>BuilderError` returned by `build()` if a required field is not set, and
+/// `<Struct>::builder()`.
+fn runtime_builder_to_tokens(
+    args: &Args,
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let item_vis = &item_struct.vis;
+    let vis = args.vis().resolve_with(item_vis);
+    let generics = &item_struct.generics;
+    let where_clause = generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(generics);
+    let generic_idents = create_generic_idents(generics);
+
+    let builder_ident = format_ident!("{}Builder", ident);
+    let error_ident = format_ident!("{}BuilderError", ident);
+
+    // the generic params would be unused if they were used only by skipped fields
+    let marker = (fields.iter().any(is_skipped) && !generics.params.is_empty()).then(|| {
+        quote! { __marker: ::core::marker::PhantomData<fn() -> #ident #generic_idents>, }
+    });
+    let marker_value = marker
+        .as_ref()
+        .map(|_| quote! { __marker: ::core::marker::PhantomData, });
+
+    let mut builder_fields = proc_macro2::TokenStream::new();
+    let mut builder_field_values = proc_macro2::TokenStream::new();
+    let mut methods = proc_macro2::TokenStream::new();
+    let mut field_values = proc_macro2::TokenStream::new();
+
+    for field in fields {
+        let Some(field_ident) = &field.field.ident else {
+            // builders are only generated for structs with named fields
+            continue;
+        };
+        let field_type = &field.field.ty;
+        let default_value = field.builder_default_value_tokens();
+
+        if is_skipped(field) {
+            let value = default_value.unwrap_or_else(|| field.default_value_tokens());
+            field_values.extend(quote! { #field_ident: #value, });
+            continue;
+        }
+
+        builder_fields.extend(quote! {
+            #field_ident: ::core::option::Option<#field_type>,
+        });
+        builder_field_values.extend(quote! {
+            #field_ident: ::core::option::Option::None,
+        });
+
+        let (value_type, value) = method_param_tokens(field);
+        methods.extend(quote! {
+            #[must_use]
+            #vis fn #field_ident(mut self, value: #value_type) -> Self {
+                self.#field_ident = ::core::option::Option::Some(#value);
+                self
+            }
+        });
+
+        let missing_value = match default_value {
+            Some(default_value) => default_value,
+            None => {
+                let field_name = field_ident.to_string();
+                quote! {
+                    return ::core::result::Result::Err(#error_ident {
+                        missing_field: #field_name,
+                    })
+                }
+            }
+        };
+        field_values.extend(quote! {
+            #field_ident: match self.#field_ident {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => #missing_value,
+            },
+        });
+    }
+
+    let error_message = format!("the `{{}}` field of `{}` is not set", ident);
+
+    tokens.extend(quote! {
+        #item_vis struct #builder_ident #generics
+        #where_clause {
+            #builder_fields
+            #marker
+        }
+
+        impl #generics_for_impl ::core::default::Default for #builder_ident #generic_idents
+        #where_clause {
+            fn default() -> Self {
+                Self {
+                    #builder_field_values
+                    #marker_value
+                }
+            }
+        }
+
+        impl #generics_for_impl #builder_ident #generic_idents
+        #where_clause {
+            #methods
+
+            #vis fn build(self) -> ::core::result::Result<#ident #generic_idents, #error_ident> {
+                ::core::result::Result::Ok(#ident {
+                    #field_values
+                })
+            }
+        }
+
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #vis fn builder() -> #builder_ident #generic_idents {
+                ::core::default::Default::default()
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #item_vis struct #error_ident {
+            missing_field: &'static str,
+        }
+
+        impl #error_ident {
+            /// name of the field that is not set
+            #vis fn missing_field(&self) -> &'static str {
+                self.missing_field
+            }
+        }
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, #error_message, self.missing_field)
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+    });
+}
+
+/// Generates `<Struct>Builder` that has a generic state param for every required field, the state
+/// is either `<Struct>BuilderUnset` or `<Struct>BuilderSet<T>` that holds the value of the field.
+/// The method of a required field exists only while the field is not set, and `build()` exists
+/// only if every required field is set, so a missing field is a compile error.
+fn typestate_builder_to_tokens(
+    args: &Args,
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let item_vis = &item_struct.vis;
+    let vis = args.vis().resolve_with(item_vis);
+    let generics = &item_struct.generics;
+    let where_clause = generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(generics);
+    let generic_idents = create_generic_idents(generics);
+
+    let builder_ident = format_ident!("{}Builder", ident);
+    let set_ident = format_ident!("{}BuilderSet", ident);
+    let unset_ident = format_ident!("{}BuilderUnset", ident);
+
+    // the generics of the struct are followed by the states of the required fields
+    let impl_params = generics_for_impl.params.iter().collect::<Vec<_>>();
+    let type_args = generic_idents.params.iter().collect::<Vec<_>>();
+
+    let fields = fields
+        .iter()
+        .filter(|field| field.field.ident.is_some())
+        .collect::<Vec<_>>();
+    let required_fields = fields
+        .iter()
+        .copied()
+        .filter(|field| !is_skipped(field) && field.builder_default_value_tokens().is_none())
+        .collect::<Vec<_>>();
+    let optional_fields = fields
+        .iter()
+        .copied()
+        .filter(|field| !is_skipped(field) && field.builder_default_value_tokens().is_some())
+        .collect::<Vec<_>>();
+    // named by the index of the field, names derived from the field names could collide (e.g.,
+    // `foo_bar` and `foo__bar` in camel case)
+    let states = required_fields
+        .iter()
+        .map(|field| format_ident!("__S{}", field.index))
+        .collect::<Vec<_>>();
+
+    let required_idents = required_fields
+        .iter()
+        .map(|field| field.field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let optional_idents = optional_fields
+        .iter()
+        .map(|field| field.field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let optional_types = optional_fields
+        .iter()
+        .map(|field| &field.field.ty)
+        .collect::<Vec<_>>();
+
+    let unset_states = required_fields
+        .iter()
+        .map(|_| quote! { #unset_ident })
+        .collect::<Vec<_>>();
+    let set_states = required_fields
+        .iter()
+        .map(|field| {
+            let field_type = &field.field.ty;
+            quote! { #set_ident<#field_type> }
+        })
+        .collect::<Vec<_>>();
+
+    let mut methods = proc_macro2::TokenStream::new();
+
+    for (index, field) in required_fields.iter().enumerate() {
+        let field_ident = required_idents[index];
+        let (value_type, value) = method_param_tokens(field);
+
+        let other_states = states
+            .iter()
+            .enumerate()
+            .filter(|(other_index, _)| *other_index != index)
+            .map(|(_, state)| state);
+        let states_before = states.iter().enumerate().map(|(other_index, state)| {
+            if other_index == index {
+                quote! { #unset_ident }
+            } else {
+                quote! { #state }
+            }
+        });
+        let states_after = states.iter().enumerate().map(|(other_index, state)| {
+            if other_index == index {
+                set_states[index].clone()
+            } else {
+                quote! { #state }
+            }
+        });
+        let other_required_idents = required_idents
+            .iter()
+            .enumerate()
+            .filter(|(other_index, _)| *other_index != index)
+            .map(|(_, ident)| ident);
+
+        methods.extend(quote! {
+            impl<#(#impl_params,)* #(#other_states,)*> #builder_ident<#(#type_args,)* #(#states_before,)*>
+            #where_clause {
+                #[must_use]
+                #vis fn #field_ident(self, value: #value_type) -> #builder_ident<#(#type_args,)* #(#states_after,)*> {
+                    #builder_ident {
+                        #field_ident: #set_ident(#value),
+                        #(#other_required_idents: self.#other_required_idents,)*
+                        #(#optional_idents: self.#optional_idents,)*
+                        __marker: ::core::marker::PhantomData,
+                    }
+                }
+            }
+        });
+    }
+
+    let mut optional_methods = proc_macro2::TokenStream::new();
+    for field in &optional_fields {
+        let field_ident = field.field.ident.as_ref().unwrap();
+        let (value_type, value) = method_param_tokens(field);
+
+        optional_methods.extend(quote! {
+            #[must_use]
+            #vis fn #field_ident(mut self, value: #value_type) -> Self {
+                self.#field_ident = ::core::option::Option::Some(#value);
+                self
+            }
+        });
+    }
+
+    let mut field_values = proc_macro2::TokenStream::new();
+    for field in &fields {
+        let field_ident = field.field.ident.as_ref().unwrap();
+        let value = if is_skipped(field) {
+            field
+                .builder_default_value_tokens()
+                .unwrap_or_else(|| field.default_value_tokens())
+        } else if let Some(default_value) = field.builder_default_value_tokens() {
+            quote! {
+                match self.#field_ident {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #default_value,
+                }
+            }
+        } else {
+            quote! { self.#field_ident.0 }
+        };
+        field_values.extend(quote! { #field_ident: #value, });
+    }
+
+    tokens.extend(quote! {
+        #item_vis struct #unset_ident;
+
+        #item_vis struct #set_ident<T>(T);
+
+        #item_vis struct #builder_ident<#(#impl_params,)* #(#states,)*>
+        #where_clause {
+            #(#required_idents: #states,)*
+            #(#optional_idents: ::core::option::Option<#optional_types>,)*
+            __marker: ::core::marker::PhantomData<fn() -> #ident #generic_idents>,
+        }
+
+        impl #generics_for_impl ::core::default::Default for #builder_ident<#(#type_args,)* #(#unset_states,)*>
+        #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#required_idents: #unset_ident,)*
+                    #(#optional_idents: ::core::option::Option::None,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #methods
+
+        impl<#(#impl_params,)* #(#states,)*> #builder_ident<#(#type_args,)* #(#states,)*>
+        #where_clause {
+            #optional_methods
+        }
+
+        impl #generics_for_impl #builder_ident<#(#type_args,)* #(#set_states,)*>
+        #where_clause {
+            #vis fn build(self) -> #ident #generic_idents {
+                #ident {
+                    #field_values
+                }
+            }
+        }
+
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #vis fn builder() -> #builder_ident<#(#type_args,)* #(#unset_states,)*> {
+                ::core::default::Default::default()
+            }
+        }
+    });
+}
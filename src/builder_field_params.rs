@@ -0,0 +1,76 @@
+use crate::{default_params::DefaultParams, suggestion};
+
+#[derive(Clone, Default)]
+pub struct BuilderFieldParams {
+    /// the field is optional in the builder, it takes precedence over the `default` directive
+    pub default: Option<DefaultParams>,
+    /// the method of the builder accepts `impl Into<T>`
+    pub into: bool,
+    /// the builder has no method for the field, the default value of the field is used
+    pub skip: bool,
+}
+
+impl BuilderFieldParams {
+    const PARAMS: &'static [&'static str] = &["default", "into", "skip"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_flag(existing: &mut bool, span: proc_macro2::Span, name: &str) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, format!("{} already specified", name)));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        let mut default = None;
+        let mut into = false;
+        let mut skip = false;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "default" => {
+                        if default.is_some() {
+                            return Err(syn::Error::new(ident.span(), "default already specified"));
+                        }
+                        default = Some(DefaultParams::parse(&content)?);
+                    }
+                    "into" => {
+                        set_flag(&mut into, ident.span(), "into")?;
+                    }
+                    "skip" => {
+                        set_flag(&mut skip, ident.span(), "skip")?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(BuilderFieldParams {
+            default,
+            into,
+            skip,
+        })
+    }
+}
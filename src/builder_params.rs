@@ -0,0 +1,57 @@
+use crate::suggestion;
+
+#[derive(Clone, Default)]
+pub struct BuilderParams {
+    /// `build()` exists only if every required field is set, it is checked at compile time
+    pub typestate: bool,
+}
+
+impl BuilderParams {
+    const PARAMS: &'static [&'static str] = &["typestate"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_typestate(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "typestate already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        let mut typestate = false;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "typestate" => {
+                        set_typestate(&mut typestate, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(BuilderParams { typestate })
+    }
+}
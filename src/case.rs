@@ -0,0 +1,23 @@
+use syn::ext::IdentExt;
+
+/// `FooBar` -> `foo_bar`, `HTTPServer` -> `http_server`
+pub fn to_snake_case(ident: &syn::Ident) -> String {
+    let chars = ident.unraw().to_string().chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(chars.len() + 4);
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 && chars[index - 1] != '_' {
+            let previous = chars[index - 1];
+            let is_next_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && is_next_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
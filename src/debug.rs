@@ -0,0 +1,198 @@
+use quote::quote;
+
+use crate::{
+    create_generic_idents, create_generics_for_impl, create_where_clause_with_bound,
+    debug_params::DebugParams, directive::DirectiveKind, syn_field::SynField,
+    syn_variant::SynVariant,
+};
+
+fn debug_params(field: &SynField) -> Option<&DebugParams> {
+    field
+        .directives
+        .iter()
+        .find_map(|directive| match &directive.kind {
+            DirectiveKind::Debug(params) => Some(params),
+            _ => None,
+        })
+}
+
+fn is_skipped(field: &SynField) -> bool {
+    matches!(debug_params(field), Some(DebugParams::Skip))
+}
+
+/// The types of the fields printed by their own `Debug` implementation, these are bounded by
+/// `Debug` in the where clause of the implementation.
+fn debug_printed_types<'a>(
+    fields: impl IntoIterator<Item = &'a SynField>,
+) -> impl Iterator<Item = &'a syn::Type> {
+    fields
+        .into_iter()
+        .filter(|field| debug_params(field).is_none())
+        .map(|field| &field.field.ty)
+}
+
+/// `__DebugWith` prints a value by a function, it is generated only if it is used by a field
+fn debug_with_tokens<'a>(
+    fields: impl IntoIterator<Item = &'a SynField>,
+) -> proc_macro2::TokenStream {
+    let is_used = fields
+        .into_iter()
+        .any(|field| matches!(debug_params(field), Some(DebugParams::With(_))));
+    if !is_used {
+        return quote! {};
+    }
+
+    quote! {
+        struct __DebugWith<'a, T: ?::core::marker::Sized>(
+            &'a T,
+            fn(&T, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+        );
+
+        impl<T: ?::core::marker::Sized> ::core::fmt::Debug for __DebugWith<'_, T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                (self.1)(self.0, f)
+            }
+        }
+    }
+}
+
+/// Expression that prints the fields by `debug_struct` or `debug_tuple`, `value` is a reference
+/// to the field. The output ends with `..` if a field is skipped.
+fn fields_to_tokens(
+    name: &str,
+    fields: &[SynField],
+    value: impl Fn(&SynField) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if fields.is_empty() {
+        return quote! { f.write_str(#name) };
+    }
+
+    let is_named = fields.iter().any(|field| field.field.ident.is_some());
+
+    let field_calls = fields
+        .iter()
+        .filter(|field| !is_skipped(field))
+        .map(|field| {
+            let value = match debug_params(field) {
+                Some(DebugParams::Redact) => quote! { &"***" },
+                Some(DebugParams::With(path)) => {
+                    let value = value(field);
+                    quote! { &__DebugWith(#value, #path) }
+                }
+                Some(DebugParams::Skip) | None => value(field),
+            };
+
+            match &field.field.ident {
+                Some(ident) => {
+                    let field_name = syn::ext::IdentExt::unraw(ident).to_string();
+                    quote! { .field(#field_name, #value) }
+                }
+                None => quote! { .field(#value) },
+            }
+        });
+
+    let finish = if fields.iter().any(is_skipped) {
+        quote! { finish_non_exhaustive }
+    } else {
+        quote! { finish }
+    };
+
+    if is_named {
+        quote! { f.debug_struct(#name) #(#field_calls)* .#finish() }
+    } else {
+        quote! { f.debug_tuple(#name) #(#field_calls)* .#finish() }
+    }
+}
+
+/// Implements `Debug` for a struct, the fields are printed according to their `debug` directives.
+pub fn struct_debug_to_tokens(
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = create_where_clause_with_bound(
+        &item_struct.generics,
+        debug_printed_types(fields),
+        &quote! { ::core::fmt::Debug },
+    );
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+
+    let debug_with = debug_with_tokens(fields);
+    let body = fields_to_tokens(&ident.to_string(), fields, |field| {
+        let member = field.member();
+        quote! { &self.#member }
+    });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::fmt::Debug for #ident #generic_idents
+        #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #debug_with
+                #body
+            }
+        }
+    });
+}
+
+/// Implements `Debug` for an enum, the fields of the variants are printed according to their
+/// `debug` directives.
+pub fn enum_debug_to_tokens(
+    item_enum: &syn::ItemEnum,
+    variants: &[SynVariant],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let where_clause = create_where_clause_with_bound(
+        &item_enum.generics,
+        debug_printed_types(variants.iter().flat_map(|variant| &variant.fields)),
+        &quote! { ::core::fmt::Debug },
+    );
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+
+    let binding = |member: &syn::Member| match member {
+        syn::Member::Named(ident) => quote::format_ident!("__self_{}", ident),
+        syn::Member::Unnamed(index) => quote::format_ident!("__self_{}", index.index),
+    };
+
+    let debug_with = debug_with_tokens(variants.iter().flat_map(|variant| &variant.fields));
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.variant.ident;
+
+        // the skipped and the redacted fields are not bound, so they are not unused variables
+        let members = variant
+            .fields
+            .iter()
+            .filter(|field| {
+                !matches!(
+                    debug_params(field),
+                    Some(DebugParams::Skip | DebugParams::Redact)
+                )
+            })
+            .map(SynField::member)
+            .collect::<Vec<_>>();
+        let bindings = members.iter().map(binding);
+        let body = fields_to_tokens(&variant_ident.to_string(), &variant.fields, |field| {
+            let binding = binding(&field.member());
+            quote! { #binding }
+        });
+
+        quote! {
+            Self::#variant_ident { #(#members: #bindings,)* .. } => #body,
+        }
+    });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::fmt::Debug for #ident #generic_idents
+        #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #debug_with
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    });
+}
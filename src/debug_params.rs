@@ -0,0 +1,56 @@
+use crate::suggestion;
+
+#[derive(Clone)]
+pub enum DebugParams {
+    /// the field is left out of the output
+    Skip,
+    /// the field is printed as `"***"`
+    Redact,
+    /// the field is printed by the given function, its signature is
+    /// `fn(&T, &mut fmt::Formatter) -> fmt::Result`
+    With(syn::Path),
+}
+
+impl DebugParams {
+    const PARAMS: &'static [&'static str] = &["skip", "redact", "with"];
+
+    /// `span` is the span of the directive
+    pub fn parse(input: syn::parse::ParseStream, span: proc_macro2::Span) -> syn::Result<Self> {
+        if !input.peek(syn::token::Paren) {
+            return Err(syn::Error::new(
+                span,
+                "expected `debug(skip)`, `debug(redact)` or `debug(with = path)`",
+            ));
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let ident: syn::Ident = content.parse()?;
+        let params = match ident.to_string().as_str() {
+            "skip" => DebugParams::Skip,
+            "redact" => DebugParams::Redact,
+            "with" => {
+                content.parse::<syn::Token![=]>()?;
+                DebugParams::With(content.parse()?)
+            }
+            other => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "expected {}, found `{}`{}",
+                        suggestion::expected(Self::PARAMS),
+                        other,
+                        suggestion::did_you_mean(other, Self::PARAMS),
+                    ),
+                ));
+            }
+        };
+
+        if !content.is_empty() {
+            return Err(content.error("`skip`, `redact` and `with` are mutually exclusive"));
+        }
+
+        Ok(params)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::suggestion;
+
+#[derive(Clone, Default)]
+pub struct DefaultImplParams {
+    /// the variant that is the default value of an enum
+    pub variant: Option<syn::Ident>,
+}
+
+impl DefaultImplParams {
+    const PARAMS: &'static [&'static str] = &["variant"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_variant(
+            existing: &mut Option<syn::Ident>,
+            new: syn::LitStr,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "variant already specified"));
+            }
+            *existing = Some(new.parse()?);
+            Ok(())
+        }
+
+        let mut variant = None;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "variant" => {
+                        content.parse::<syn::Token![=]>()?;
+                        let variant_lit: syn::LitStr = content.parse()?;
+                        set_variant(&mut variant, variant_lit, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(DefaultImplParams { variant })
+    }
+}
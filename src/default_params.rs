@@ -0,0 +1,25 @@
+#[derive(Clone)]
+pub struct DefaultParams {
+    /// `None` means `::core::default::Default::default()`
+    pub expr: Option<syn::Expr>,
+}
+
+impl DefaultParams {
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let expr = if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(DefaultParams { expr })
+    }
+
+    pub fn value_tokens(&self) -> proc_macro2::TokenStream {
+        match &self.expr {
+            Some(expr) => quote::quote! { #expr },
+            None => quote::quote! { ::core::default::Default::default() },
+        }
+    }
+}
@@ -1,16 +1,29 @@
-use crate::{access_params::AccessParams, get_mut_params::GetMutParams, get_params::GetParams};
+use quote::format_ident;
+use syn::ext::IdentExt;
+
+use crate::{
+    access_params::AccessParams, builder_field_params::BuilderFieldParams,
+    builder_params::BuilderParams, case, debug_params::DebugParams,
+    default_impl_params::DefaultImplParams, default_params::DefaultParams,
+    display_params::DisplayParams, from_params::FromParams, get_mut_params::GetMutParams,
+    get_params::GetParams, into_params::IntoParams, key_params::KeyParams, kind_params::KindParams,
+    new_params::NewParams, set_params::SetParams, skip_params::SkipParams, suggestion,
+    with_params::WithParams,
+};
 
 #[derive(Clone)]
 pub enum DirectiveKind {
-    From,
-    Into,
-    Convert,
+    From(FromParams),
+    Into(IntoParams),
+    Convert(FromParams),
 
     GetRef(GetParams),
     GetCopy(GetParams),
     GetClone(GetParams),
     GetMut(GetMutParams),
     Access(AccessParams),
+    Set(SetParams),
+    With(WithParams),
 
     AsRef,
     AsMut,
@@ -18,11 +31,196 @@ pub enum DirectiveKind {
 
     Deref,
     DerefMut,
+
+    Default(DefaultParams),
+    Skip(SkipParams),
+
+    /// container-level, generates a builder type for the struct
+    Builder(BuilderParams),
+    /// field-level, controls the method of the field in the builder
+    BuilderField(BuilderFieldParams),
+    /// container-level, generates a constructor for the struct
+    New(NewParams),
+    /// container-level, implements `Default` by the `default` directives of the fields
+    DefaultImpl(DefaultImplParams),
+
+    Display(DisplayParams),
+
+    /// container-level, implements `std::error::Error`
+    ErrorImpl,
+    /// field-level, the field is returned by `Error::source()`
+    Source,
+
+    /// container-level, implements `Debug` by the `debug` directives of the fields
+    DebugImpl,
+    /// field-level, controls how the field is printed by the `Debug` implementation
+    Debug(DebugParams),
+
+    /// field-level, the comparison traits of the struct compare only the key fields
+    Key(KeyParams),
+
+    /// variant-level, `is_<variant>(&self) -> bool`
+    Is(GetParams),
+    /// variant-level, `as_<variant>(&self) -> Option<&T>`
+    AsVariant(GetParams),
+    /// variant-level, `as_<variant>_mut(&mut self) -> Option<&mut T>`
+    AsVariantMut(GetParams),
+    /// variant-level, `into_<variant>(self) -> Result<T, Self>`
+    IntoVariant(GetParams),
+
+    /// container-level, generates a fieldless enum with the variants of the enum
+    Kind(KindParams),
+}
+
+/// the place where the directive is written, it determines which directives are accepted
+#[derive(Copy, Clone)]
+pub enum DirectiveScope {
+    Struct,
+    StructField,
+    Enum,
+    EnumVariant,
+    EnumVariantField,
 }
 
+impl DirectiveScope {
+    fn description(&self) -> &'static str {
+        match self {
+            DirectiveScope::Struct => "structs",
+            DirectiveScope::StructField => "struct fields",
+            DirectiveScope::Enum => "enums",
+            DirectiveScope::EnumVariant => "enum variants",
+            DirectiveScope::EnumVariantField => "enum variant fields",
+        }
+    }
+
+    pub fn accepted_directives(&self) -> &'static [&'static str] {
+        match self {
+            DirectiveScope::Struct => &[
+                "get_ref",
+                "get_copy",
+                "get_clone",
+                "get_mut",
+                "access",
+                "set",
+                "with",
+                "builder",
+                "new",
+                "default",
+                "display",
+                "error",
+                "debug_impl",
+            ],
+            DirectiveScope::StructField => &[
+                "from",
+                "into",
+                "convert",
+                "get_ref",
+                "get_copy",
+                "get_clone",
+                "get_mut",
+                "access",
+                "set",
+                "with",
+                "as_ref",
+                "as_mut",
+                "as",
+                "deref",
+                "deref_mut",
+                "default",
+                "skip",
+                "builder",
+                "display",
+                "source",
+                "debug",
+                "key",
+            ],
+            DirectiveScope::Enum => &[
+                "from",
+                "into",
+                "convert",
+                "default",
+                "display",
+                "error",
+                "debug_impl",
+                "is",
+                "as_variant",
+                "as_variant_mut",
+                "into_variant",
+                "kind",
+            ],
+            DirectiveScope::EnumVariant => &[
+                "from",
+                "skip",
+                "display",
+                "is",
+                "as_variant",
+                "as_variant_mut",
+                "into_variant",
+            ],
+            DirectiveScope::EnumVariantField => &[
+                "from", "into", "convert", "default", "skip", "display", "source", "debug",
+            ],
+        }
+    }
+
+    /// container-level directives that are applied to every field (or to the field of every
+    /// single-field variant), these are the ones that can be opted out by `skip`
+    pub fn field_directives(&self) -> &'static [&'static str] {
+        match self {
+            DirectiveScope::Struct | DirectiveScope::StructField => &[
+                "get_ref",
+                "get_copy",
+                "get_clone",
+                "get_mut",
+                "access",
+                "set",
+                "with",
+            ],
+            DirectiveScope::Enum
+            | DirectiveScope::EnumVariant
+            | DirectiveScope::EnumVariantField => &["from", "into", "convert"],
+        }
+    }
+
+    /// container-level directives that are applied to every variant of an enum
+    pub fn variant_directives(&self) -> &'static [&'static str] {
+        match self {
+            DirectiveScope::Enum | DirectiveScope::EnumVariant => {
+                &["is", "as_variant", "as_variant_mut", "into_variant"]
+            }
+            DirectiveScope::Struct
+            | DirectiveScope::StructField
+            | DirectiveScope::EnumVariantField => &[],
+        }
+    }
+
+    /// container-level directives that can be opted out by `skip`, a variant opts out of the ones
+    /// applied to itself and of the ones applied to its field
+    pub fn skippable_directives(&self) -> &'static [&'static str] {
+        match self {
+            DirectiveScope::EnumVariant => &[
+                "from",
+                "into",
+                "convert",
+                "is",
+                "as_variant",
+                "as_variant_mut",
+                "into_variant",
+            ],
+            DirectiveScope::Struct
+            | DirectiveScope::StructField
+            | DirectiveScope::Enum
+            | DirectiveScope::EnumVariantField => self.field_directives(),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Directive {
     pub span: proc_macro2::Span,
     pub kind: DirectiveKind,
+    /// the tokens of the directive, container-level directives are parsed again for every field
+    pub tokens: proc_macro2::TokenStream,
 }
 
 impl Directive {
@@ -32,17 +230,53 @@ impl Directive {
 }
 
 impl DirectiveKind {
+    /// names of every known directive, in the order of declaration
+    pub const NAMES: &'static [&'static str] = &[
+        "from",
+        "into",
+        "convert",
+        "get_ref",
+        "get_copy",
+        "get_clone",
+        "get_mut",
+        "access",
+        "set",
+        "with",
+        "as_ref",
+        "as_mut",
+        "as",
+        "deref",
+        "deref_mut",
+        "default",
+        "skip",
+        "builder",
+        "new",
+        "display",
+        "error",
+        "source",
+        "debug_impl",
+        "debug",
+        "key",
+        "is",
+        "as_variant",
+        "as_variant_mut",
+        "into_variant",
+        "kind",
+    ];
+
     fn name(&self) -> &'static str {
         match self {
-            DirectiveKind::From => "from",
-            DirectiveKind::Into => "into",
-            DirectiveKind::Convert => "convert",
+            DirectiveKind::From(_) => "from",
+            DirectiveKind::Into(_) => "into",
+            DirectiveKind::Convert(_) => "convert",
 
             DirectiveKind::GetRef(_) => "get_ref",
             DirectiveKind::GetCopy(_) => "get_copy",
             DirectiveKind::GetClone(_) => "get_clone",
             DirectiveKind::GetMut(_) => "get_mut",
             DirectiveKind::Access(_) => "access",
+            DirectiveKind::Set(_) => "set",
+            DirectiveKind::With(_) => "with",
 
             DirectiveKind::AsRef => "as_ref",
             DirectiveKind::AsMut => "as_mut",
@@ -50,171 +284,133 @@ impl DirectiveKind {
 
             DirectiveKind::Deref => "deref",
             DirectiveKind::DerefMut => "deref_mut",
+
+            DirectiveKind::Default(_) => "default",
+            DirectiveKind::Skip(_) => "skip",
+
+            DirectiveKind::Builder(_) | DirectiveKind::BuilderField(_) => "builder",
+            DirectiveKind::New(_) => "new",
+            DirectiveKind::DefaultImpl(_) => "default",
+
+            DirectiveKind::Display(_) => "display",
+
+            DirectiveKind::ErrorImpl => "error",
+            DirectiveKind::Source => "source",
+
+            DirectiveKind::DebugImpl => "debug_impl",
+            DirectiveKind::Debug(_) => "debug",
+
+            DirectiveKind::Key(_) => "key",
+
+            DirectiveKind::Is(_) => "is",
+            DirectiveKind::AsVariant(_) => "as_variant",
+            DirectiveKind::AsVariantMut(_) => "as_variant_mut",
+            DirectiveKind::IntoVariant(_) => "into_variant",
+
+            DirectiveKind::Kind(_) => "kind",
+        }
+    }
+
+    /// names of the methods generated by the directive
+    pub fn method_names(&self) -> Vec<&syn::Ident> {
+        match self {
+            DirectiveKind::GetRef(GetParams { name, .. })
+            | DirectiveKind::GetCopy(GetParams { name, .. })
+            | DirectiveKind::GetClone(GetParams { name, .. })
+            | DirectiveKind::GetMut(GetMutParams { name, .. })
+            | DirectiveKind::Set(SetParams { name, .. })
+            | DirectiveKind::With(WithParams { name, .. })
+            | DirectiveKind::Is(GetParams { name, .. })
+            | DirectiveKind::AsVariant(GetParams { name, .. })
+            | DirectiveKind::AsVariantMut(GetParams { name, .. })
+            | DirectiveKind::IntoVariant(GetParams { name, .. }) => vec![name],
+            DirectiveKind::Access(AccessParams {
+                get_name,
+                get_mut_name,
+                set_name,
+                ..
+            }) => [get_name, get_mut_name]
+                .into_iter()
+                .chain(set_name.as_ref())
+                .collect(),
+            DirectiveKind::From(_)
+            | DirectiveKind::Into(_)
+            | DirectiveKind::Convert(_)
+            | DirectiveKind::AsRef
+            | DirectiveKind::AsMut
+            | DirectiveKind::As
+            | DirectiveKind::Deref
+            | DirectiveKind::DerefMut
+            | DirectiveKind::Default(_)
+            | DirectiveKind::Skip(_)
+            | DirectiveKind::Builder(_)
+            | DirectiveKind::BuilderField(_)
+            | DirectiveKind::New(_)
+            | DirectiveKind::DefaultImpl(_)
+            | DirectiveKind::Display(_)
+            | DirectiveKind::ErrorImpl
+            | DirectiveKind::Source
+            | DirectiveKind::DebugImpl
+            | DirectiveKind::Debug(_)
+            | DirectiveKind::Key(_)
+            | DirectiveKind::Kind(_) => vec![],
         }
     }
 
     pub fn is_conflicted_with(&self, other: &DirectiveKind) -> bool {
+        let other_method_names = other.method_names();
+        if self
+            .method_names()
+            .iter()
+            .any(|name| other_method_names.contains(name))
+        {
+            return true;
+        }
+
         match self {
-            DirectiveKind::From => match other {
-                DirectiveKind::From | DirectiveKind::Convert => true,
-                DirectiveKind::Into
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::Into => match other {
-                DirectiveKind::Into | DirectiveKind::Convert => true,
-                DirectiveKind::From
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::Convert => match other {
-                DirectiveKind::From | DirectiveKind::Into | DirectiveKind::Convert => true,
-                DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::GetRef(GetParams { name })
-            | DirectiveKind::GetCopy(GetParams { name })
-            | DirectiveKind::GetClone(GetParams { name })
-            | DirectiveKind::GetMut(GetMutParams { name }) => match other {
-                DirectiveKind::GetRef(GetParams { name: other_name })
-                | DirectiveKind::GetCopy(GetParams { name: other_name })
-                | DirectiveKind::GetClone(GetParams { name: other_name })
-                | DirectiveKind::GetMut(GetMutParams { name: other_name }) => *name == *other_name,
-                DirectiveKind::Access(AccessParams {
-                    get_name,
-                    get_mut_name,
-                    ..
-                }) => *name == *get_name || *name == *get_mut_name,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::Access(params) => match other {
-                DirectiveKind::GetRef(GetParams { name })
-                | DirectiveKind::GetCopy(GetParams { name })
-                | DirectiveKind::GetClone(GetParams { name })
-                | DirectiveKind::GetMut(GetMutParams { name }) => {
-                    params.get_name == *name || params.get_mut_name == *name
-                }
-                DirectiveKind::Access(AccessParams {
-                    get_name,
-                    get_mut_name,
-                    ..
-                }) => {
-                    params.get_name == *get_name
-                        || params.get_name == *get_mut_name
-                        || params.get_mut_name == *get_name
-                        || params.get_mut_name == *get_mut_name
-                }
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::AsRef => match other {
-                DirectiveKind::AsRef | DirectiveKind::As => true,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsMut
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::AsMut => match other {
-                DirectiveKind::AsMut | DirectiveKind::As => true,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::As => match other {
-                DirectiveKind::AsRef | DirectiveKind::AsMut | DirectiveKind::As => true,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::Deref
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::Deref => match other {
-                DirectiveKind::Deref => true,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::DerefMut => false,
-            },
-            DirectiveKind::DerefMut => match other {
-                DirectiveKind::DerefMut => true,
-                DirectiveKind::From
-                | DirectiveKind::Into
-                | DirectiveKind::Convert
-                | DirectiveKind::GetRef(_)
-                | DirectiveKind::GetCopy(_)
-                | DirectiveKind::GetClone(_)
-                | DirectiveKind::GetMut(_)
-                | DirectiveKind::Access(_)
-                | DirectiveKind::AsRef
-                | DirectiveKind::AsMut
-                | DirectiveKind::As
-                | DirectiveKind::Deref => false,
-            },
+            DirectiveKind::From(_) => {
+                matches!(other, DirectiveKind::From(_) | DirectiveKind::Convert(_))
+            }
+            DirectiveKind::Into(_) => {
+                matches!(other, DirectiveKind::Into(_) | DirectiveKind::Convert(_))
+            }
+            DirectiveKind::Convert(_) => matches!(
+                other,
+                DirectiveKind::From(_) | DirectiveKind::Into(_) | DirectiveKind::Convert(_)
+            ),
+            // the generated methods are compared by their names above
+            DirectiveKind::GetRef(_)
+            | DirectiveKind::GetCopy(_)
+            | DirectiveKind::GetClone(_)
+            | DirectiveKind::GetMut(_)
+            | DirectiveKind::Access(_)
+            | DirectiveKind::Set(_)
+            | DirectiveKind::With(_) => false,
+            DirectiveKind::AsRef => matches!(other, DirectiveKind::AsRef | DirectiveKind::As),
+            DirectiveKind::AsMut => matches!(other, DirectiveKind::AsMut | DirectiveKind::As),
+            DirectiveKind::As => matches!(
+                other,
+                DirectiveKind::AsRef | DirectiveKind::AsMut | DirectiveKind::As
+            ),
+            DirectiveKind::Deref => matches!(other, DirectiveKind::Deref),
+            DirectiveKind::DerefMut => matches!(other, DirectiveKind::DerefMut),
+            DirectiveKind::Default(_) => matches!(other, DirectiveKind::Default(_)),
+            DirectiveKind::Skip(_) => matches!(other, DirectiveKind::Skip(_)),
+            DirectiveKind::Builder(_) => matches!(other, DirectiveKind::Builder(_)),
+            DirectiveKind::BuilderField(_) => matches!(other, DirectiveKind::BuilderField(_)),
+            DirectiveKind::New(_) => matches!(other, DirectiveKind::New(_)),
+            DirectiveKind::DefaultImpl(_) => matches!(other, DirectiveKind::DefaultImpl(_)),
+            DirectiveKind::Display(_) => matches!(other, DirectiveKind::Display(_)),
+            DirectiveKind::ErrorImpl => matches!(other, DirectiveKind::ErrorImpl),
+            DirectiveKind::Source => matches!(other, DirectiveKind::Source),
+            DirectiveKind::DebugImpl => matches!(other, DirectiveKind::DebugImpl),
+            DirectiveKind::Debug(_) => matches!(other, DirectiveKind::Debug(_)),
+            DirectiveKind::Key(_) => matches!(other, DirectiveKind::Key(_)),
+            DirectiveKind::Is(_) => matches!(other, DirectiveKind::Is(_)),
+            DirectiveKind::AsVariant(_) => matches!(other, DirectiveKind::AsVariant(_)),
+            DirectiveKind::AsVariantMut(_) => matches!(other, DirectiveKind::AsVariantMut(_)),
+            DirectiveKind::IntoVariant(_) => matches!(other, DirectiveKind::IntoVariant(_)),
+            DirectiveKind::Kind(_) => matches!(other, DirectiveKind::Kind(_)),
         }
     }
 }
@@ -229,18 +425,39 @@ impl Directive {
     pub fn parse(
         input: syn::parse::ParseStream,
         default_name: Option<syn::Ident>,
+        scope: DirectiveScope,
     ) -> syn::Result<Self> {
-        let ident: syn::Ident = input.parse()?;
-        let kind = match ident.to_string().as_str() {
-            "from" => DirectiveKind::From,
-            "into" => DirectiveKind::Into,
-            "convert" => DirectiveKind::Convert,
+        // `as` is a keyword, so keywords have to be accepted as well
+        let ident = input.call(syn::Ident::parse_any)?;
+        let ident_str = ident.to_string();
+
+        let accepted_directives = scope.accepted_directives();
+        if DirectiveKind::NAMES.contains(&ident_str.as_str())
+            && !accepted_directives.contains(&ident_str.as_str())
+        {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "directive `{}` is not supported on {}, expected {}",
+                    ident,
+                    scope.description(),
+                    suggestion::expected(accepted_directives),
+                ),
+            ));
+        }
+
+        let kind = match ident_str.as_str() {
+            "from" => DirectiveKind::From(FromParams::parse(input)?),
+            "into" => DirectiveKind::Into(IntoParams::parse(input)?),
+            "convert" => DirectiveKind::Convert(FromParams::parse(input)?),
 
             "get_ref" => DirectiveKind::GetRef(GetParams::parse(input, default_name)?),
             "get_clone" => DirectiveKind::GetClone(GetParams::parse(input, default_name)?),
             "get_copy" => DirectiveKind::GetCopy(GetParams::parse(input, default_name)?),
             "get_mut" => DirectiveKind::GetMut(GetMutParams::parse(input, default_name)?),
             "access" => DirectiveKind::Access(AccessParams::parse(input, default_name)?),
+            "set" => DirectiveKind::Set(SetParams::parse(input, default_name)?),
+            "with" => DirectiveKind::With(WithParams::parse(input, default_name)?),
 
             "as_ref" => DirectiveKind::AsRef,
             "as_mut" => DirectiveKind::AsMut,
@@ -249,10 +466,61 @@ impl Directive {
             "deref" => DirectiveKind::Deref,
             "deref_mut" => DirectiveKind::DerefMut,
 
+            "default" => match scope {
+                DirectiveScope::Struct | DirectiveScope::Enum => {
+                    DirectiveKind::DefaultImpl(DefaultImplParams::parse(input)?)
+                }
+                DirectiveScope::StructField
+                | DirectiveScope::EnumVariant
+                | DirectiveScope::EnumVariantField => {
+                    DirectiveKind::Default(DefaultParams::parse(input)?)
+                }
+            },
+            "skip" => DirectiveKind::Skip(SkipParams::parse(input, scope.skippable_directives())?),
+
+            "builder" => match scope {
+                DirectiveScope::Struct => DirectiveKind::Builder(BuilderParams::parse(input)?),
+                _ => DirectiveKind::BuilderField(BuilderFieldParams::parse(input)?),
+            },
+            "new" => DirectiveKind::New(NewParams::parse(input)?),
+
+            "display" => DirectiveKind::Display(DisplayParams::parse(input, scope, ident.span())?),
+
+            "error" => DirectiveKind::ErrorImpl,
+            "source" => DirectiveKind::Source,
+
+            "debug_impl" => DirectiveKind::DebugImpl,
+            "debug" => DirectiveKind::Debug(DebugParams::parse(input, ident.span())?),
+
+            "key" => DirectiveKind::Key(KeyParams::parse(input)?),
+
+            "is" => DirectiveKind::Is(GetParams::parse(
+                input,
+                default_name.map(|name| format_ident!("is_{}", case::to_snake_case(&name))),
+            )?),
+            "as_variant" => DirectiveKind::AsVariant(GetParams::parse(
+                input,
+                default_name.map(|name| format_ident!("as_{}", case::to_snake_case(&name))),
+            )?),
+            "as_variant_mut" => DirectiveKind::AsVariantMut(GetParams::parse(
+                input,
+                default_name.map(|name| format_ident!("as_{}_mut", case::to_snake_case(&name))),
+            )?),
+            "into_variant" => DirectiveKind::IntoVariant(GetParams::parse(
+                input,
+                default_name.map(|name| format_ident!("into_{}", case::to_snake_case(&name))),
+            )?),
+
+            "kind" => DirectiveKind::Kind(KindParams::parse(input)?),
+
             _ => {
                 return Err(syn::Error::new(
                     ident.span(),
-                    format!("unknown directive `{}`", ident),
+                    format!(
+                        "unknown directive `{}`{}",
+                        ident,
+                        suggestion::did_you_mean(&ident_str, accepted_directives),
+                    ),
                 ));
             }
         };
@@ -260,6 +528,7 @@ impl Directive {
         Ok(Directive {
             span: ident.span(),
             kind,
+            tokens: proc_macro2::TokenStream::new(),
         })
     }
 }
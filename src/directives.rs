@@ -1,6 +1,8 @@
-use syn::punctuated::Punctuated;
-
-use crate::directive::Directive;
+use crate::{
+    ATTRIBUTE_NAME, are_path_segments_equal,
+    directive::{Directive, DirectiveScope},
+    errors::Errors,
+};
 
 #[derive(Default)]
 pub struct Directives {
@@ -8,54 +10,258 @@ pub struct Directives {
 }
 
 impl Directives {
-    pub fn extend_from(&mut self, other: Self) -> syn::Result<()> {
+    /// conflicting directives are reported to `errors` and left out
+    pub fn extend_from(&mut self, other: Self, errors: &mut Errors) {
         for directive in other.directives {
-            if let Some(excluded_by) = excluded_by(&self.directives, &directive) {
-                return Err(syn::Error::new(
-                    directive.span(),
-                    format!(
-                        "directives `{}` and `{}` are mutually exclusive",
-                        directive.kind, excluded_by.kind,
-                    ),
-                ));
-            }
+            self.push(directive, errors);
+        }
+    }
 
-            self.directives.push(directive);
+    /// a conflicting directive is reported to `errors` and left out
+    pub fn push(&mut self, directive: Directive, errors: &mut Errors) {
+        if let Some(excluded_by) = excluded_by(&self.directives, &directive) {
+            let excluded_by_method_names = excluded_by.kind.method_names();
+            let message = match directive
+                .kind
+                .method_names()
+                .into_iter()
+                .find(|name| excluded_by_method_names.contains(name))
+            {
+                Some(method_name) => format!(
+                    "directives `{}` and `{}` both generate the method `{}`",
+                    directive.kind, excluded_by.kind, method_name,
+                ),
+                None => format!(
+                    "directives `{}` and `{}` are mutually exclusive",
+                    directive.kind, excluded_by.kind,
+                ),
+            };
+            errors.push(syn::Error::new(directive.span(), message));
+            return;
         }
 
-        Ok(())
+        self.directives.push(directive);
+    }
+
+    /// Reports the directives that generate the same method as a directive of another field or
+    /// variant, the clashes within the directives of one field are reported by `push`. `owner` is
+    /// the name of the field or the variant in the error message.
+    pub fn check_method_names<'a>(
+        all_directives: impl IntoIterator<Item = (String, &'a Directives)>,
+        errors: &mut Errors,
+    ) {
+        let mut generated: Vec<(&syn::Ident, &Directive, String)> = Vec::new();
+        for (owner, directives) in all_directives {
+            let mut own = Vec::new();
+            for directive in &directives.directives {
+                for method_name in directive.kind.method_names() {
+                    match generated.iter().find(|(name, ..)| *name == method_name) {
+                        Some((_, other, other_owner)) => errors.push(syn::Error::new(
+                            directive.span(),
+                            format!(
+                                "the method `{}` is already generated by the `{}` directive of `{}`",
+                                method_name, other.kind, other_owner,
+                            ),
+                        )),
+                        None => own.push((method_name, directive, owner.clone())),
+                    }
+                }
+            }
+            generated.extend(own);
+        }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &Directive> {
         self.directives.iter()
     }
+
+    /// the directives for which `predicate` returns `true`
+    pub fn filtered(&self, predicate: impl Fn(&Directive) -> bool) -> Self {
+        Directives {
+            directives: self
+                .directives
+                .iter()
+                .filter(|directive| predicate(directive))
+                .cloned()
+                .collect(),
+        }
+    }
 }
 
 impl Directives {
+    /// parses and removes the `#[attrimpl(...)]` attributes
+    pub fn parse_attributes(
+        attrs: &mut Vec<syn::Attribute>,
+        default_name: Option<syn::Ident>,
+        scope: DirectiveScope,
+        errors: &mut Errors,
+    ) -> Self {
+        let mut directives = Directives::default();
+
+        let mut i = 0;
+        while i < attrs.len() {
+            let attr = &attrs[i];
+
+            if let syn::Meta::List(attr) = &attr.meta
+                && are_path_segments_equal(&attr.path.segments, &[ATTRIBUTE_NAME])
+            {
+                let mut attr_errors = Errors::default();
+                let tmp = syn::parse::Parser::parse2(
+                    |input: syn::parse::ParseStream<'_>| {
+                        Directives::parse(input, default_name.clone(), scope, &mut attr_errors)
+                    },
+                    attr.tokens.clone(),
+                );
+                match attr_errors.finish() {
+                    // the tokens left behind by the invalid directives would only cause
+                    // follow-up errors, so the result of the parser is not reported
+                    Err(error) => errors.push(error),
+                    Ok(()) => {
+                        if let Some(tmp) = errors.handle(tmp) {
+                            directives.extend_from(tmp, errors);
+                        }
+                    }
+                }
+
+                attrs.swap_remove(i);
+
+                continue;
+            }
+
+            if are_path_segments_equal(&attr.path().segments, &[ATTRIBUTE_NAME, "display"]) {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    "`#[attrimpl::display(...)]` is not supported, use `#[attrimpl(display(...))]` instead",
+                ));
+                attrs.swap_remove(i);
+
+                continue;
+            }
+
+            i += 1;
+        }
+
+        directives
+    }
+
+    /// Parses and removes the `#[attrimpl(...)]` attributes of a struct or an enum. The directives
+    /// are parsed with a placeholder name, they are parsed again for every field with the name of
+    /// the field.
+    pub fn parse_container_attributes(
+        attrs: &mut Vec<syn::Attribute>,
+        placeholder_name: syn::Ident,
+        scope: DirectiveScope,
+        errors: &mut Errors,
+    ) -> Self {
+        let directives = Self::parse_attributes(attrs, Some(placeholder_name), scope, errors);
+
+        for directive in directives.iter() {
+            let name = directive.kind.to_string();
+            let is_field_directive = scope.field_directives().contains(&name.as_str())
+                || scope.variant_directives().contains(&name.as_str());
+
+            // the same name would be given to the generated method of every field or variant
+            if is_field_directive && let Some(span) = find_name_param(&directive.tokens) {
+                errors.push(syn::Error::new(
+                    span,
+                    format!(
+                        "`name` cannot be specified for the container-level `{}` directive",
+                        directive.kind,
+                    ),
+                ));
+            }
+        }
+
+        directives
+    }
+
     pub fn parse(
         input: syn::parse::ParseStream,
         default_name: Option<syn::Ident>,
+        scope: DirectiveScope,
+        errors: &mut Errors,
     ) -> syn::Result<Self> {
-        // the code below is copied from syn::punctuated::Punctuated::parse_terminated
-        let mut directives = Punctuated::new();
+        // the code below is based on syn::punctuated::Punctuated::parse_terminated, but invalid
+        // directives are collected into `errors` and skipped, so the rest can be checked as well
+        let mut directives = Vec::new();
 
         loop {
             if input.is_empty() {
                 break;
             }
-            let value = Directive::parse(input, default_name.clone())?;
-            directives.push_value(value);
+            let begin = input.cursor();
+            match Directive::parse(input, default_name.clone(), scope) {
+                Ok(mut value) => {
+                    value.tokens = tokens_between(begin, input.cursor());
+                    directives.push(value);
+                }
+                Err(error) => {
+                    errors.push(error);
+                    skip_directive(input)?;
+                }
+            }
             if input.is_empty() {
                 break;
             }
-            let punct = input.parse::<syn::Token![,]>()?;
-            directives.push_punct(punct);
+            input.parse::<syn::Token![,]>()?;
         }
 
-        Ok(Directives {
-            directives: directives.into_iter().collect(),
+        Ok(Directives { directives })
+    }
+}
+
+fn find_name_param(tokens: &proc_macro2::TokenStream) -> Option<proc_macro2::Span> {
+    tokens
+        .clone()
+        .into_iter()
+        .find_map(|token_tree| match token_tree {
+            proc_macro2::TokenTree::Group(group)
+                if group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+            {
+                group
+                    .stream()
+                    .into_iter()
+                    .find_map(|token_tree| match token_tree {
+                        proc_macro2::TokenTree::Ident(ident) if ident == "name" => {
+                            Some(ident.span())
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
         })
+}
+
+fn tokens_between(
+    begin: syn::buffer::Cursor,
+    end: syn::buffer::Cursor,
+) -> proc_macro2::TokenStream {
+    let mut tokens = proc_macro2::TokenStream::new();
+    let mut cursor = begin;
+    while cursor != end {
+        let Some((token_tree, next)) = cursor.token_tree() else {
+            break;
+        };
+        tokens.extend([token_tree]);
+        cursor = next;
     }
+    tokens
+}
+
+/// skips the tokens until the next `,` that separates the directives
+fn skip_directive(input: syn::parse::ParseStream) -> syn::Result<()> {
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        while let Some((token_tree, next)) = rest.token_tree() {
+            match &token_tree {
+                proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                    return Ok(((), rest));
+                }
+                _ => rest = next,
+            }
+        }
+        Ok(((), rest))
+    })
 }
 
 fn excluded_by<'a>(directives: &'a [Directive], directive: &Directive) -> Option<&'a Directive> {
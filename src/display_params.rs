@@ -0,0 +1,76 @@
+use crate::directive::DirectiveScope;
+
+#[derive(Clone)]
+pub enum DisplayParams {
+    /// `display` on a struct or on an enum, the format is given by the field with
+    /// `display(forward)`, or by the variants of the enum
+    Delegated,
+    /// `display("...")` on a struct or on an enum variant
+    Format(syn::LitStr),
+    /// `display(forward)` on a field, the `Display` implementation of the field is used
+    Forward,
+}
+
+impl DisplayParams {
+    /// the accepted params depend on the scope, `span` is the span of the directive
+    pub fn parse(
+        input: syn::parse::ParseStream,
+        scope: DirectiveScope,
+        span: proc_macro2::Span,
+    ) -> syn::Result<Self> {
+        match scope {
+            DirectiveScope::Enum => {
+                if input.peek(syn::token::Paren) {
+                    return Err(syn::Error::new(
+                        span,
+                        "the format strings of an enum are given by its variants (e.g., `#[attrimpl(display(\"...\"))]` on a variant)",
+                    ));
+                }
+                Ok(DisplayParams::Delegated)
+            }
+            DirectiveScope::Struct | DirectiveScope::EnumVariant => {
+                let expected = "expected a format string (e.g., `display(\"{name} ({id})\")`)";
+                if !input.peek(syn::token::Paren) {
+                    // the struct is displayed by its field with `display(forward)`
+                    if matches!(scope, DirectiveScope::Struct) {
+                        return Ok(DisplayParams::Delegated);
+                    }
+                    return Err(syn::Error::new(span, expected));
+                }
+
+                let content;
+                syn::parenthesized!(content in input);
+                let format = content
+                    .parse::<syn::LitStr>()
+                    .map_err(|error| syn::Error::new(error.span(), expected))?;
+                if !content.is_empty() {
+                    return Err(content.error(
+                        "format arguments are not supported, the fields can be referred in the format string (e.g., `{name}` or `{0}`)",
+                    ));
+                }
+
+                Ok(DisplayParams::Format(format))
+            }
+            DirectiveScope::StructField | DirectiveScope::EnumVariantField => {
+                if !input.peek(syn::token::Paren) {
+                    return Err(syn::Error::new(span, "expected `display(forward)`"));
+                }
+
+                let content;
+                syn::parenthesized!(content in input);
+                let param = content.parse::<syn::Ident>()?;
+                if param != "forward" {
+                    return Err(syn::Error::new(
+                        param.span(),
+                        format!("expected `forward`, found `{}`", param),
+                    ));
+                }
+                if !content.is_empty() {
+                    return Err(content.error("expected `)`"));
+                }
+
+                Ok(DisplayParams::Forward)
+            }
+        }
+    }
+}
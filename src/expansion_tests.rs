@@ -0,0 +1,121 @@
+//! Snapshot tests of the generated code.
+//!
+//! Every `tests/expand/<name>.rs` fixture is expanded by the same pipeline as the macro, the result
+//! is pretty-printed and compared with `tests/expand/<name>.expanded.rs`. After an intended change
+//! of the generated code, the snapshots can be updated by running
+//! `ATTRIMPL_SNAPSHOTS=overwrite cargo test --lib`.
+
+use std::path::Path;
+
+use quote::ToTokens;
+
+use crate::{ATTRIBUTE_NAME, are_path_segments_equal, expand};
+
+const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/expand");
+
+fn is_macro_attribute(attr: &syn::Attribute) -> bool {
+    let segments = &attr.path().segments;
+    are_path_segments_equal(segments, &[ATTRIBUTE_NAME, ATTRIBUTE_NAME])
+        || are_path_segments_equal(segments, &[ATTRIBUTE_NAME])
+}
+
+/// expands every item of the fixture that has the macro attribute, the other items are kept as
+/// they are
+fn expand_fixture(source: &str) -> String {
+    let file: syn::File = syn::parse_str(source).expect("fixture is not valid Rust code");
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    for mut item in file.items {
+        let attrs = match &mut item {
+            syn::Item::Struct(item_struct) => &mut item_struct.attrs,
+            syn::Item::Enum(item_enum) => &mut item_enum.attrs,
+            _ => {
+                item.to_tokens(&mut tokens);
+                continue;
+            }
+        };
+
+        let Some(position) = attrs.iter().position(is_macro_attribute) else {
+            item.to_tokens(&mut tokens);
+            continue;
+        };
+
+        let args = match attrs.remove(position).meta {
+            syn::Meta::Path(_) => proc_macro2::TokenStream::new(),
+            syn::Meta::List(list) => list.tokens,
+            syn::Meta::NameValue(_) => panic!("unexpected macro attribute"),
+        };
+
+        tokens.extend(expand(args, item.into_token_stream()));
+    }
+
+    let file: syn::File = syn::parse2(tokens).expect("expansion is not valid Rust code");
+    prettyplease::unparse(&file)
+}
+
+#[test]
+fn expansions() {
+    let overwrite = std::env::var("ATTRIMPL_SNAPSHOTS").is_ok_and(|value| value == "overwrite");
+
+    let mut fixtures = std::fs::read_dir(FIXTURE_DIR)
+        .expect("fixture directory is missing")
+        .map(|entry| entry.expect("cannot read fixture directory").path())
+        .filter(|path| {
+            path.extension().is_some_and(|extension| extension == "rs")
+                && !path.to_string_lossy().ends_with(".expanded.rs")
+        })
+        .collect::<Vec<_>>();
+    fixtures.sort();
+
+    let mut mismatches = Vec::new();
+    for fixture in &fixtures {
+        let source = std::fs::read_to_string(fixture).expect("cannot read fixture");
+        let actual = expand_fixture(&source);
+
+        let snapshot = fixture.with_extension("expanded.rs");
+        if overwrite {
+            std::fs::write(&snapshot, &actual).expect("cannot write snapshot");
+            continue;
+        }
+
+        match std::fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => {
+                mismatches.push(snapshot_mismatch(&snapshot, &expected, &actual));
+            }
+            Err(_) => mismatches.push(format!("{}: snapshot is missing", snapshot.display())),
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{}\n\nrun `ATTRIMPL_SNAPSHOTS=overwrite cargo test --lib` to update the snapshots",
+        mismatches.join("\n\n"),
+    );
+}
+
+fn snapshot_mismatch(snapshot: &Path, expected: &str, actual: &str) -> String {
+    let first_difference = expected
+        .lines()
+        .zip(actual.lines())
+        .position(|(expected, actual)| expected != actual)
+        .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+
+    format!(
+        "{}: snapshot differs from line {}\n--- expected\n{}\n+++ actual\n{}",
+        snapshot.display(),
+        first_difference + 1,
+        expected
+            .lines()
+            .skip(first_difference)
+            .take(10)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        actual
+            .lines()
+            .skip(first_difference)
+            .take(10)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
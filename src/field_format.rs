@@ -0,0 +1,129 @@
+use quote::quote;
+use syn::ext::IdentExt;
+
+use crate::{create_where_clause_with_bound, suggestion, syn_field::SynField};
+
+/// A format string whose placeholders refer to the fields by name (e.g., `{name}`) or by index
+/// (e.g., `{0:>8}`).
+pub struct FieldFormat {
+    /// the indices are replaced by `_<index>`, so every placeholder is a named argument
+    pub format: syn::LitStr,
+    /// the referenced fields with the names of their arguments, every field is listed once
+    pub args: Vec<(syn::Member, syn::Ident)>,
+    /// the types of the referenced fields with the formatting traits required by the placeholders
+    /// (e.g., `Debug` for `{name:?}`)
+    bounds: Vec<(syn::Type, proc_macro2::TokenStream)>,
+}
+
+impl FieldFormat {
+    pub fn parse(format: &syn::LitStr, fields: &[SynField]) -> syn::Result<Self> {
+        let value = format.value();
+        let field_names = fields
+            .iter()
+            .map(|field| match &field.field.ident {
+                Some(ident) => ident.unraw().to_string(),
+                None => field.index.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = String::with_capacity(value.len());
+        let mut args: Vec<(syn::Member, syn::Ident)> = Vec::new();
+        let mut bounds = Vec::new();
+        let mut chars = value.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            result.push(c);
+            if c != '{' {
+                continue;
+            }
+            if chars.peek() == Some(&'{') {
+                result.push(chars.next().unwrap());
+                continue;
+            }
+
+            let mut placeholder = String::new();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(c) => placeholder.push(c),
+                    None => {
+                        return Err(syn::Error::new(
+                            format.span(),
+                            "invalid format string, expected `}`",
+                        ));
+                    }
+                }
+            }
+
+            let (arg, spec) = match placeholder.find(':') {
+                Some(index) => placeholder.split_at(index),
+                None => (placeholder.as_str(), ""),
+            };
+            let arg = arg.trim();
+
+            let Some(field_index) = field_names.iter().position(|name| name == arg) else {
+                let message = if arg.is_empty() {
+                    "positional arguments are not supported, the fields can be referred by name (e.g., `{name}`) or by index (e.g., `{0}`)".to_string()
+                } else {
+                    let names = field_names.iter().map(String::as_str).collect::<Vec<_>>();
+                    format!(
+                        "unknown field `{}` in the format string{}",
+                        arg,
+                        suggestion::did_you_mean(arg, &names),
+                    )
+                };
+                return Err(syn::Error::new(format.span(), message));
+            };
+
+            let field = &fields[field_index];
+            let arg_ident = match &field.field.ident {
+                Some(ident) => syn::Ident::new(&ident.unraw().to_string(), format.span()),
+                None => quote::format_ident!("_{}", field.index, span = format.span()),
+            };
+
+            result.push_str(&arg_ident.to_string());
+            result.push_str(spec);
+            result.push('}');
+
+            if !args.iter().any(|(_, ident)| *ident == arg_ident) {
+                args.push((field.member(), arg_ident));
+            }
+            bounds.push((field.field.ty.clone(), format_trait(spec)));
+        }
+
+        Ok(FieldFormat {
+            format: syn::LitStr::new(&result, format.span()),
+            args,
+            bounds,
+        })
+    }
+
+    /// Where clause of the generics extended by the formatting traits of the referenced fields
+    /// whose types depend on a type parameter.
+    pub fn where_clause(&self, generics: &syn::Generics) -> Option<syn::WhereClause> {
+        let mut generics = generics.clone();
+        for (ty, bound) in &self.bounds {
+            generics.where_clause = create_where_clause_with_bound(&generics, [ty], bound);
+        }
+        generics.where_clause
+    }
+}
+
+/// The formatting trait of a placeholder by the type at the end of its spec (e.g., `Debug` for
+/// `:?` or `:#x?`, `LowerHex` for `:08x`), `Display` if there is no type.
+fn format_trait(spec: &str) -> proc_macro2::TokenStream {
+    if spec.ends_with('?') {
+        return quote! { ::core::fmt::Debug };
+    }
+
+    match spec.chars().last() {
+        Some('x') => quote! { ::core::fmt::LowerHex },
+        Some('X') => quote! { ::core::fmt::UpperHex },
+        Some('o') => quote! { ::core::fmt::Octal },
+        Some('b') => quote! { ::core::fmt::Binary },
+        Some('e') => quote! { ::core::fmt::LowerExp },
+        Some('E') => quote! { ::core::fmt::UpperExp },
+        Some('p') => quote! { ::core::fmt::Pointer },
+        _ => quote! { ::core::fmt::Display },
+    }
+}
@@ -0,0 +1,94 @@
+use crate::suggestion;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub enum FromDefaults {
+    /// the struct or variant has no other fields
+    #[default]
+    None,
+    /// the other fields are filled with `Default::default()` or their `default = <..>` value
+    Field,
+    /// the other fields are filled from the `Default` implementation of the container
+    Container,
+}
+
+#[derive(Clone, Default)]
+pub struct FromParams {
+    pub defaults: FromDefaults,
+    /// `From<Box<T>>` is also implemented for the container and for the boxed container
+    pub boxed: bool,
+}
+
+impl FromParams {
+    const PARAMS: &'static [&'static str] = &["field_default", "container_default", "boxed"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_defaults(
+            existing: &mut Option<FromDefaults>,
+            new: FromDefaults,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`field_default` and `container_default` are mutually exclusive",
+                ));
+            }
+            *existing = Some(new);
+            Ok(())
+        }
+
+        fn set_boxed(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "boxed already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        let mut defaults = None;
+        let mut boxed = false;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "field_default" => {
+                        set_defaults(&mut defaults, FromDefaults::Field, ident.span())?;
+                    }
+                    "container_default" => {
+                        set_defaults(&mut defaults, FromDefaults::Container, ident.span())?;
+                    }
+                    "boxed" => {
+                        set_boxed(&mut boxed, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(FromParams {
+            defaults: defaults.unwrap_or_default(),
+            boxed,
+        })
+    }
+}
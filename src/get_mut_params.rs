@@ -1,8 +1,10 @@
-use crate::get_params::GetParams;
+use crate::{get_params::GetParams, vis_param::VisParam};
 
 #[derive(Clone)]
 pub struct GetMutParams {
     pub name: syn::Ident,
+    /// `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
 }
 
 impl GetMutParams {
@@ -10,10 +12,10 @@ impl GetMutParams {
         input: syn::parse::ParseStream,
         default_name: Option<syn::Ident>,
     ) -> syn::Result<Self> {
-        let GetParams { name } = GetParams::parse(input, default_name)?;
+        let GetParams { name, vis } = GetParams::parse(input, default_name)?;
 
-        let name = syn::Ident::new(&format!("{}_mut", name), name.span());
+        let name = quote::format_ident!("{}_mut", name, span = name.span());
 
-        Ok(GetMutParams { name })
+        Ok(GetMutParams { name, vis })
     }
 }
@@ -1,9 +1,15 @@
+use crate::{suggestion, vis_param::VisParam};
+
 #[derive(Clone)]
 pub struct GetParams {
     pub name: syn::Ident,
+    /// `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
 }
 
 impl GetParams {
+    const PARAMS: &'static [&'static str] = &["name", "vis"];
+
     pub fn parse(
         input: syn::parse::ParseStream,
         default_name: Option<syn::Ident>,
@@ -20,7 +26,20 @@ impl GetParams {
             Ok(())
         }
 
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "vis already specified"));
+            }
+            *existing = Some(VisParam::parse_value(input)?);
+            Ok(())
+        }
+
         let mut name = None;
+        let mut vis = None;
 
         if input.peek(syn::token::Paren) {
             let content;
@@ -35,12 +54,17 @@ impl GetParams {
                         let name_lit: syn::LitStr = content.parse()?;
                         set_name(&mut name, name_lit, ident.span())?;
                     }
+                    "vis" => {
+                        set_vis(&mut vis, &content, ident.span())?;
+                    }
                     other => {
                         return Err(syn::Error::new(
                             ident.span(),
                             format!(
-                                "expected one of `get_ref`, `get_copy`, `get_clone`, or `name = <..>`, found `{}`",
-                                other
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
                             ),
                         ));
                     }
@@ -64,6 +88,6 @@ impl GetParams {
             })?,
         };
 
-        Ok(GetParams { name })
+        Ok(GetParams { name, vis })
     }
 }
@@ -0,0 +1,58 @@
+use crate::suggestion;
+
+#[derive(Clone, Default)]
+pub struct IntoParams {
+    /// `Into<Box<T>>` is also implemented for the container, and `Into<T>` and `Into<Box<T>>` are
+    /// implemented for the boxed container
+    pub boxed: bool,
+}
+
+impl IntoParams {
+    const PARAMS: &'static [&'static str] = &["boxed"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_boxed(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "boxed already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        let mut boxed = false;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "boxed" => {
+                        set_boxed(&mut boxed, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(IntoParams { boxed })
+    }
+}
@@ -1,23 +1,23 @@
 use syn::parse::discouraged::Speculative;
 
-use crate::{syn_item_enum::SynItemEnum, syn_item_struct::SynItemStruct};
+use crate::{args::Args, syn_item_enum::SynItemEnum, syn_item_struct::SynItemStruct};
 
 pub enum Item {
     Struct(SynItemStruct),
     Enum(SynItemEnum),
 }
 
-impl syn::parse::Parse for Item {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+impl Item {
+    pub fn parse(input: syn::parse::ParseStream, args: &Args) -> syn::Result<Self> {
         let fork_struct = input.fork();
         let fork_enum = input.fork();
 
         if let Ok(item_struct) = fork_struct.parse::<syn::ItemStruct>() {
             input.advance_to(&fork_struct);
-            Ok(Item::Struct(SynItemStruct::parse(item_struct)?))
+            Ok(Item::Struct(SynItemStruct::parse(item_struct, args)?))
         } else if let Ok(item_enum) = fork_enum.parse::<syn::ItemEnum>() {
             input.advance_to(&fork_enum);
-            Ok(Item::Enum(SynItemEnum::parse(item_enum)?))
+            Ok(Item::Enum(SynItemEnum::parse(item_enum, args)?))
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -0,0 +1,57 @@
+use crate::suggestion;
+
+#[derive(Clone, Default)]
+pub struct KeyParams {
+    /// implements `Borrow<T>` for the struct where `T` is the type of the key field
+    pub borrow: bool,
+}
+
+impl KeyParams {
+    const PARAMS: &'static [&'static str] = &["borrow"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_borrow(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "borrow already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        let mut borrow = false;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "borrow" => {
+                        set_borrow(&mut borrow, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(KeyParams { borrow })
+    }
+}
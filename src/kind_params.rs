@@ -0,0 +1,125 @@
+use crate::{suggestion, vis_param::VisParam};
+
+#[derive(Clone, Default)]
+pub struct KindParams {
+    /// name of the generated enum, `None` means `<Enum>Kind`
+    pub name: Option<syn::Ident>,
+    /// derived traits of the generated enum in addition to the ones that are always derived
+    pub derives: Vec<syn::Path>,
+    /// visibility of `kind()`, `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
+}
+
+impl KindParams {
+    const PARAMS: &'static [&'static str] = &["name", "derive", "vis"];
+
+    /// the traits that are always derived for the generated enum
+    pub const DERIVES: &'static [&'static str] =
+        &["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_name(
+            existing: &mut Option<syn::Ident>,
+            new: syn::LitStr,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "name already specified"));
+            }
+            *existing = Some(new.parse()?);
+            Ok(())
+        }
+
+        fn set_derives(
+            existing: &mut Option<Vec<syn::Path>>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "derive already specified"));
+            }
+
+            let content;
+            syn::parenthesized!(content in input);
+            let paths = syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated(
+                &content,
+            )?;
+
+            for path in &paths {
+                if let Some(segment) = path.segments.last()
+                    && KindParams::DERIVES.contains(&segment.ident.to_string().as_str())
+                {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        format!("`{}` is always derived for the kind enum", segment.ident),
+                    ));
+                }
+            }
+
+            *existing = Some(paths.into_iter().collect());
+            Ok(())
+        }
+
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "vis already specified"));
+            }
+            *existing = Some(VisParam::parse_value(input)?);
+            Ok(())
+        }
+
+        let mut name = None;
+        let mut derives = None;
+        let mut vis = None;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "name" => {
+                        content.parse::<syn::Token![=]>()?;
+                        let name_lit: syn::LitStr = content.parse()?;
+                        set_name(&mut name, name_lit, ident.span())?;
+                    }
+                    "derive" => {
+                        set_derives(&mut derives, &content, ident.span())?;
+                    }
+                    "vis" => {
+                        set_vis(&mut vis, &content, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(KindParams {
+            name,
+            derives: derives.unwrap_or_default(),
+            vis,
+        })
+    }
+}
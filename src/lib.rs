@@ -1,20 +1,43 @@
 mod access_params;
 mod args;
+mod builder;
+mod builder_field_params;
+mod builder_params;
+mod case;
+mod debug;
+mod debug_params;
+mod default_impl_params;
+mod default_params;
 mod directive;
 mod directives;
+mod display_params;
+mod errors;
+#[cfg(test)]
+mod expansion_tests;
+mod field_format;
+mod from_params;
 mod get_mut_params;
 mod get_params;
+mod into_params;
 mod item;
+mod key_params;
+mod kind_params;
+mod new_params;
+mod set_params;
+mod skip_params;
+mod suggestion;
 mod syn_field;
 mod syn_item_enum;
 mod syn_item_struct;
 mod syn_variant;
+mod vis_param;
+mod with_params;
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{punctuated::Punctuated, token};
 
-use crate::{args::Args, item::Item};
+use crate::{args::Args, errors::Errors, item::Item};
 
 const ATTRIBUTE_NAME: &str = "attrimpl";
 
@@ -32,6 +55,42 @@ fn are_path_segments_equal(
         .all(|(seg, ident)| seg.ident == ident)
 }
 
+/// `#[attrimpl::display(...)]` is reported as an error, it is stripped as well, so the compiler
+/// does not report it again.
+fn is_helper_attribute(attr: &syn::Attribute) -> bool {
+    let segments = &attr.path().segments;
+    are_path_segments_equal(segments, &[ATTRIBUTE_NAME])
+        || are_path_segments_equal(segments, &[ATTRIBUTE_NAME, "display"])
+}
+
+/// Returns the item without the helper attributes, so the item can be emitted as it is when the
+/// expansion fails. This way the rest of the crate still sees the type, and only the actual
+/// errors are reported.
+fn strip_helper_attributes(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    fn strip_fields(fields: &mut syn::Fields) {
+        for field in fields.iter_mut() {
+            field.attrs.retain(|attr| !is_helper_attribute(attr));
+        }
+    }
+
+    match syn::parse2::<syn::Item>(input.clone()) {
+        Ok(syn::Item::Struct(mut item_struct)) => {
+            item_struct.attrs.retain(|attr| !is_helper_attribute(attr));
+            strip_fields(&mut item_struct.fields);
+            quote! { #item_struct }
+        }
+        Ok(syn::Item::Enum(mut item_enum)) => {
+            item_enum.attrs.retain(|attr| !is_helper_attribute(attr));
+            for variant in &mut item_enum.variants {
+                variant.attrs.retain(|attr| !is_helper_attribute(attr));
+                strip_fields(&mut variant.fields);
+            }
+            quote! { #item_enum }
+        }
+        _ => input,
+    }
+}
+
 fn create_generic_idents(generics: &syn::Generics) -> syn::Generics {
     syn::Generics {
         params: generics
@@ -86,19 +145,127 @@ fn create_generics_for_impl(generics: &syn::Generics) -> syn::Generics {
     generics
 }
 
+/// Where clause of the generics extended by `type: bound` for every type that depends on a type
+/// parameter, e.g. `T: Debug` for a printed field of type `T`. The other types are not bounded,
+/// the compiler checks those anyway, and a bound on a private type would be a `private_bounds`
+/// warning.
+fn create_where_clause_with_bound<'a>(
+    generics: &syn::Generics,
+    types: impl IntoIterator<Item = &'a syn::Type>,
+    bound: &proc_macro2::TokenStream,
+) -> Option<syn::WhereClause> {
+    fn uses_type_params(tokens: proc_macro2::TokenStream, type_params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => type_params.contains(&&ident),
+            proc_macro2::TokenTree::Group(group) => uses_type_params(group.stream(), type_params),
+            _ => false,
+        })
+    }
+
+    let type_params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect::<Vec<_>>();
+
+    let mut where_clause = generics.where_clause.clone();
+    for ty in types {
+        if !uses_type_params(quote! { #ty }, &type_params) {
+            continue;
+        }
+
+        let predicate: syn::WherePredicate = syn::parse_quote! { #ty: #bound };
+        let predicate_str = quote! { #predicate }.to_string();
+        let where_clause = where_clause.get_or_insert_with(|| syn::WhereClause {
+            where_token: Default::default(),
+            predicates: Punctuated::new(),
+        });
+        if !where_clause
+            .predicates
+            .iter()
+            .any(|existing| quote! { #existing }.to_string() == predicate_str)
+        {
+            where_clause.predicates.push(predicate);
+        }
+    }
+
+    where_clause
+}
+
+/// Local `__AsDynError` trait of the generated `Error::source()`, it converts the source field to
+/// `&(dyn Error + 'static)`. A cast works only for sized types, but the source can also be a
+/// `Box<dyn Error + Send + Sync>`, which dereferences to a trait object.
+fn as_dyn_error_tokens() -> proc_macro2::TokenStream {
+    quote! {
+        trait __AsDynError {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static);
+        }
+
+        impl<T: ::std::error::Error + 'static> __AsDynError for T {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl __AsDynError for dyn ::std::error::Error + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl __AsDynError for dyn ::std::error::Error + ::core::marker::Send + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl __AsDynError
+            for dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static
+        {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+    }
+}
+
 #[proc_macro_attribute]
 pub fn attrimpl(arg: TokenStream, input: TokenStream) -> TokenStream {
-    let args: Args = syn::parse_macro_input!(arg);
+    expand(arg.into(), input.into()).into()
+}
 
-    let item: Item = syn::parse_macro_input!(input);
+fn expand(
+    arg: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut errors = Errors::default();
 
-    let tokens = quote! {
-        #item
-    };
+    let args = errors.handle(syn::parse2::<Args>(arg));
+    // the item is checked even if the arguments are invalid, so every error is reported at once
+    let item = errors.handle(syn::parse::Parser::parse2(
+        |input: syn::parse::ParseStream<'_>| {
+            Item::parse(input, args.as_ref().unwrap_or(&Args::default()))
+        },
+        input.clone(),
+    ));
 
-    if args.debug() {
-        eprintln!("{}", tokens);
-    }
+    match (args, item) {
+        (Some(args), Some(item)) => {
+            let tokens = quote! {
+                #item
+            };
 
-    tokens.into()
+            if args.debug() {
+                eprintln!("{}", tokens);
+            }
+
+            tokens
+        }
+        _ => {
+            let mut tokens = strip_helper_attributes(input);
+            if let Err(error) = errors.finish() {
+                tokens.extend(error.to_compile_error());
+            }
+            tokens
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use crate::{suggestion, vis_param::VisParam};
+
+#[derive(Clone, Default)]
+pub struct NewParams {
+    /// the parameters of the constructor are `impl Into<T>`
+    pub into: bool,
+    /// `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
+}
+
+impl NewParams {
+    const PARAMS: &'static [&'static str] = &["into", "vis"];
+
+    pub fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        fn set_into(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "into already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "vis already specified"));
+            }
+            *existing = Some(VisParam::parse_value(input)?);
+            Ok(())
+        }
+
+        let mut into = false;
+        let mut vis = None;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "into" => {
+                        set_into(&mut into, ident.span())?;
+                    }
+                    "vis" => {
+                        set_vis(&mut vis, &content, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(NewParams { into, vis })
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{suggestion, vis_param::VisParam};
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub enum SetReturn {
+    /// nothing is returned
+    #[default]
+    Unit,
+    /// `&mut Self` is returned, so setters can be chained
+    Chain,
+    /// the previous value of the field is returned
+    Previous,
+}
+
+#[derive(Clone)]
+pub struct SetParams {
+    pub name: syn::Ident,
+    /// the setter accepts `impl Into<T>`
+    pub into: bool,
+    pub returns: SetReturn,
+    /// `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
+}
+
+impl SetParams {
+    const PARAMS: &'static [&'static str] = &["name", "into", "chain", "previous", "vis"];
+
+    pub fn parse(
+        input: syn::parse::ParseStream,
+        default_name: Option<syn::Ident>,
+    ) -> syn::Result<Self> {
+        fn set_name(
+            existing: &mut Option<syn::Ident>,
+            new: syn::LitStr,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "name already specified"));
+            }
+            *existing = Some(syn::Ident::new(&new.value(), new.span()));
+            Ok(())
+        }
+
+        fn set_into(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "into already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        fn set_returns(
+            existing: &mut Option<SetReturn>,
+            new: SetReturn,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`chain` and `previous` are mutually exclusive",
+                ));
+            }
+            *existing = Some(new);
+            Ok(())
+        }
+
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "vis already specified"));
+            }
+            *existing = Some(VisParam::parse_value(input)?);
+            Ok(())
+        }
+
+        let mut name = None;
+        let mut into = false;
+        let mut returns = None;
+        let mut vis = None;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "name" => {
+                        content.parse::<syn::Token![=]>()?;
+                        let name_lit: syn::LitStr = content.parse()?;
+                        set_name(&mut name, name_lit, ident.span())?;
+                    }
+                    "into" => {
+                        set_into(&mut into, ident.span())?;
+                    }
+                    "chain" => {
+                        set_returns(&mut returns, SetReturn::Chain, ident.span())?;
+                    }
+                    "previous" => {
+                        set_returns(&mut returns, SetReturn::Previous, ident.span())?;
+                    }
+                    "vis" => {
+                        set_vis(&mut vis, &content, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        let name = match name {
+            Some(name) => name,
+            None => default_name.ok_or_else(|| {
+                syn::Error::new(
+                    input.span(),
+                    "name must be specified if no default name is provided",
+                )
+            })?,
+        };
+        let name = quote::format_ident!("set_{}", name, span = name.span());
+
+        Ok(SetParams {
+            name,
+            into,
+            returns: returns.unwrap_or_default(),
+            vis,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use crate::suggestion;
+
+#[derive(Clone)]
+pub struct SkipParams {
+    /// `None` means that none of the container-level directives are applied to the field
+    pub directives: Option<Vec<syn::Ident>>,
+}
+
+impl SkipParams {
+    pub fn parse(
+        input: syn::parse::ParseStream,
+        container_directives: &[&str],
+    ) -> syn::Result<Self> {
+        if !input.peek(syn::token::Paren) {
+            return Ok(SkipParams { directives: None });
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let mut directives: Vec<syn::Ident> = Vec::new();
+
+        while !content.is_empty() {
+            let ident: syn::Ident = content.parse()?;
+            let ident_str = ident.to_string();
+
+            if !container_directives.contains(&ident_str.as_str()) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "expected {}, found `{}`{}",
+                        suggestion::expected(container_directives),
+                        ident,
+                        suggestion::did_you_mean(&ident_str, container_directives),
+                    ),
+                ));
+            }
+            if directives.contains(&ident) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{}` already specified", ident),
+                ));
+            }
+            directives.push(ident);
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        Ok(SkipParams {
+            directives: Some(directives),
+        })
+    }
+
+    pub fn skips(&self, directive_name: &str) -> bool {
+        match &self.directives {
+            Some(directives) => directives
+                .iter()
+                .any(|directive| directive == directive_name),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// Returns the candidate that is closest to `found`, if it is close enough to be a typo.
+pub fn closest<'a>(found: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = found.chars().count().max(3) / 3;
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(found, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns `", did you mean `<candidate>`?"` or an empty string if there is no close candidate.
+pub fn did_you_mean(found: &str, candidates: &[&str]) -> String {
+    closest(found, candidates)
+        .map(|candidate| format!(", did you mean `{}`?", candidate))
+        .unwrap_or_default()
+}
+
+/// Formats the candidates as `` `a` ``, `` `a` or `b` `` or `` one of `a`, `b`, or `c` ``.
+pub fn expected(candidates: &[&str]) -> String {
+    let quoted = candidates
+        .iter()
+        .map(|candidate| format!("`{}`", candidate))
+        .collect::<Vec<_>>();
+
+    match quoted.as_slice() {
+        [] => String::new(),
+        [single] => single.clone(),
+        [first, second] => format!("{} or {}", first, second),
+        [init @ .., last] => format!("one of {}, or {}", init.join(", "), last),
+    }
+}
+
+/// Optimal string alignment distance, i.e., Levenshtein distance where swapping two adjacent
+/// characters counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
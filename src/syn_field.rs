@@ -1,4 +1,9 @@
-use crate::{ATTRIBUTE_NAME, are_path_segments_equal, directives::Directives};
+use crate::{
+    builder_field_params::BuilderFieldParams,
+    directive::{Directive, DirectiveKind, DirectiveScope},
+    directives::Directives,
+    errors::Errors,
+};
 
 pub struct SynField {
     pub index: usize,
@@ -7,41 +12,16 @@ pub struct SynField {
 }
 
 impl SynField {
-    pub fn parse(fields: &mut syn::Fields) -> syn::Result<Vec<Self>> {
+    pub fn parse(
+        fields: &mut syn::Fields,
+        scope: DirectiveScope,
+        errors: &mut Errors,
+    ) -> Vec<Self> {
         let mut result_fields = Vec::new();
 
         for (field_index, field) in fields.iter_mut().enumerate() {
-            let mut directives = Directives::default();
-
-            let mut i = 0;
-            while i < field.attrs.len() {
-                let attr = &field.attrs[i];
-
-                if let syn::Meta::List(attr) = &attr.meta {
-                    if are_path_segments_equal(&attr.path.segments, &[ATTRIBUTE_NAME]) {
-                        let tmp = syn::parse::Parser::parse2(
-                            |input: syn::parse::ParseStream<'_>| {
-                                Directives::parse(input, field.ident.clone())
-                            },
-                            attr.tokens.clone(),
-                        )?;
-                        directives.extend_from(tmp)?;
-
-                        field.attrs.swap_remove(i);
-
-                        continue;
-                    } else if are_path_segments_equal(
-                        &attr.path.segments,
-                        &[ATTRIBUTE_NAME, "display"],
-                    ) {
-                        field.attrs.swap_remove(i);
-
-                        continue;
-                    }
-                }
-
-                i += 1;
-            }
+            let directives =
+                Directives::parse_attributes(&mut field.attrs, field.ident.clone(), scope, errors);
 
             result_fields.push(SynField {
                 index: field_index,
@@ -50,6 +30,173 @@ impl SynField {
             });
         }
 
-        Ok(result_fields)
+        result_fields
+    }
+}
+
+impl SynField {
+    /// the name of the field, or its index in a tuple struct or a tuple variant
+    pub fn member(&self) -> syn::Member {
+        match &self.field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(self.index)),
+        }
+    }
+
+    /// `member: value` of every field with its default value, it can be used for every kind of
+    /// struct and variant (e.g., `Self { 0: value }` or `Self::Unit {}`)
+    pub fn default_field_values(fields: &[SynField]) -> proc_macro2::TokenStream {
+        fields
+            .iter()
+            .map(|field| {
+                let member = field.member();
+                let value = field.default_value_tokens();
+                quote::quote! { #member: #value, }
+            })
+            .collect()
+    }
+
+    /// value of the `default` directive of the field, if there is one
+    pub fn explicit_default_value_tokens(&self) -> Option<proc_macro2::TokenStream> {
+        self.directives
+            .iter()
+            .find_map(|directive| match &directive.kind {
+                DirectiveKind::Default(params) => Some(params.value_tokens()),
+                _ => None,
+            })
+    }
+
+    /// value of the field when it is not given explicitly (e.g., `from(field_default)`)
+    pub fn default_value_tokens(&self) -> proc_macro2::TokenStream {
+        self.explicit_default_value_tokens()
+            .unwrap_or_else(|| quote::quote! { ::core::default::Default::default() })
+    }
+
+    /// params of the `builder` directive of the field, if there is one
+    pub fn builder_params(&self) -> Option<&BuilderFieldParams> {
+        self.directives
+            .iter()
+            .find_map(|directive| match &directive.kind {
+                DirectiveKind::BuilderField(params) => Some(params),
+                _ => None,
+            })
+    }
+
+    /// Value of the field when it is not set in the builder, `None` means that the field is
+    /// required. The `default` param of the `builder` directive takes precedence over the
+    /// `default` directive of the field.
+    pub fn builder_default_value_tokens(&self) -> Option<proc_macro2::TokenStream> {
+        if let Some(params) = self.builder_params() {
+            if let Some(default) = &params.default {
+                return Some(default.value_tokens());
+            }
+            if params.skip {
+                return Some(self.default_value_tokens());
+            }
+        }
+
+        self.explicit_default_value_tokens()
+    }
+
+    /// Index of the field returned by `Error::source()`: the field marked by `source`, or else the
+    /// field with a `from` or `convert` directive. It has to be called before the container-level
+    /// directives are applied, so a `from` directive of an enum does not make every field a source.
+    pub fn error_source(
+        fields: &[SynField],
+        has_error: bool,
+        errors: &mut Errors,
+    ) -> Option<usize> {
+        let mut source = None;
+        for field in fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::Source = directive.kind {
+                    if !has_error {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "the `source` directive requires the container-level `error` directive",
+                        ));
+                    } else if source.is_some() {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "`source` can be specified only for one field",
+                        ));
+                    }
+                    source = Some(field.index);
+                }
+            }
+        }
+        if !has_error {
+            return None;
+        }
+        if source.is_some() {
+            return source;
+        }
+
+        for field in fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::From(_) | DirectiveKind::Convert(_) = directive.kind {
+                    if source.is_some() {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "multiple fields have a `from` or `convert` directive, the source of the error has to be marked by `source`",
+                        ));
+                    }
+                    source = Some(field.index);
+                }
+            }
+        }
+        source
+    }
+}
+
+impl SynField {
+    /// Applies the container-level directives to the field, unless the field opts out by `skip`, or
+    /// it overrides the directive by specifying a directive of the same kind (or a conflicting one).
+    pub fn apply_container_directives(
+        &mut self,
+        container_directives: &Directives,
+        scope: DirectiveScope,
+        errors: &mut Errors,
+    ) {
+        let own_directive_count = self.directives.iter().count();
+
+        for container_directive in container_directives.iter() {
+            let name = container_directive.kind.to_string();
+
+            // e.g., `builder` is a directive of the container itself
+            if !scope.field_directives().contains(&name.as_str()) {
+                continue;
+            }
+
+            let is_skipped = self
+                .directives
+                .iter()
+                .any(|directive| match &directive.kind {
+                    DirectiveKind::Skip(params) => params.skips(&name),
+                    _ => false,
+                });
+            if is_skipped {
+                continue;
+            }
+
+            let directive = syn::parse::Parser::parse2(
+                |input: syn::parse::ParseStream<'_>| {
+                    Directive::parse(input, self.field.ident.clone(), scope)
+                },
+                container_directive.tokens.clone(),
+            );
+            let Some(directive) = errors.handle(directive) else {
+                continue;
+            };
+
+            let is_overridden = self.directives.iter().take(own_directive_count).any(|own| {
+                own.kind.to_string() == name || own.kind.is_conflicted_with(&directive.kind)
+            });
+            if is_overridden {
+                continue;
+            }
+
+            self.directives.push(directive, errors);
+        }
     }
 }
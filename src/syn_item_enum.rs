@@ -1,25 +1,193 @@
 use quote::quote;
 
 use crate::{
-    create_generic_idents, create_generics_for_impl,
-    directive::{Directive, DirectiveKind},
+    args::Args,
+    as_dyn_error_tokens, create_generic_idents, create_generics_for_impl,
+    create_where_clause_with_bound, debug,
+    directive::{Directive, DirectiveKind, DirectiveScope},
+    directives::Directives,
+    display_params::DisplayParams,
+    errors::Errors,
+    field_format::FieldFormat,
+    from_params::{FromDefaults, FromParams},
+    into_params::IntoParams,
+    kind_params::KindParams,
+    suggestion,
+    syn_field::SynField,
     syn_variant::SynVariant,
 };
 
 pub struct SynItemEnum {
     item_enum: syn::ItemEnum,
+    /// container-level directives, the ones applied to the fields are also stored by the fields
+    directives: Directives,
     variants: Vec<SynVariant>,
+    args: Args,
 }
 
 impl SynItemEnum {
-    pub fn parse(mut item_enum: syn::ItemEnum) -> syn::Result<Self> {
+    pub fn parse(mut item_enum: syn::ItemEnum, args: &Args) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+
+        let container_directives = Directives::parse_container_attributes(
+            &mut item_enum.attrs,
+            item_enum.ident.clone(),
+            DirectiveScope::Enum,
+            &mut errors,
+        );
+
+        let mut variants = item_enum
+            .variants
+            .iter_mut()
+            .map(|variant| SynVariant::parse(variant, &mut errors))
+            .collect::<Vec<_>>();
+
+        let has_error = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::ErrorImpl));
+        for variant in &mut variants {
+            // the container-level `from` directive does not make the fields sources
+            variant.error_source = SynField::error_source(&variant.fields, has_error, &mut errors);
+        }
+
+        for variant in &mut variants {
+            variant.apply_container_directives(&container_directives, &mut errors);
+        }
+        Directives::check_method_names(
+            variants
+                .iter()
+                .map(|variant| (variant.variant.ident.to_string(), &variant.directives)),
+            &mut errors,
+        );
+
+        // every unit variant implements `From<()>`, so only one of them can have `from`
+        let mut unit_from_variant: Option<&syn::Ident> = None;
+        for variant in &variants {
+            for directive in variant.directives.iter() {
+                if let DirectiveKind::From(params) = &directive.kind {
+                    if !variant.fields.is_empty() {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "the `from` directive of a variant requires a unit variant, the `from` directive of a field can be used instead",
+                        ));
+                    } else if params.defaults != FromDefaults::None || params.boxed {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "the `from` directive of a unit variant does not accept params",
+                        ));
+                    } else if let Some(other_variant) = unit_from_variant {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            format!(
+                                "the `from` directive can be specified only for one unit variant, `From<()>` is already implemented by `{}`",
+                                other_variant
+                            ),
+                        ));
+                    } else {
+                        unit_from_variant = Some(&variant.variant.ident);
+                    }
+                }
+            }
+        }
+
+        for variant in &variants {
+            for field in &variant.fields {
+                for directive in field.directives.iter() {
+                    if let DirectiveKind::From(params) | DirectiveKind::Convert(params) =
+                        &directive.kind
+                    {
+                        match params.defaults {
+                            FromDefaults::None if variant.fields.len() > 1 => {
+                                errors.push(syn::Error::new(
+                                    directive.span(),
+                                    format!(
+                                        "`{}` on a variant with multiple fields requires `field_default`",
+                                        directive.kind,
+                                    ),
+                                ));
+                            }
+                            FromDefaults::Container => {
+                                errors.push(syn::Error::new(
+                                    directive.span(),
+                                    "`container_default` is not supported for enum variants",
+                                ));
+                            }
+                            FromDefaults::None | FromDefaults::Field => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        for directive in container_directives.iter() {
+            if let DirectiveKind::DefaultImpl(params) = &directive.kind {
+                match &params.variant {
+                    Some(variant_ident) => {
+                        if !variants
+                            .iter()
+                            .any(|variant| variant.variant.ident == *variant_ident)
+                        {
+                            let variant_names = variants
+                                .iter()
+                                .map(|variant| variant.variant.ident.to_string())
+                                .collect::<Vec<_>>();
+                            let variant_names =
+                                variant_names.iter().map(String::as_str).collect::<Vec<_>>();
+                            errors.push(syn::Error::new(
+                                variant_ident.span(),
+                                format!(
+                                    "unknown variant `{}`{}",
+                                    variant_ident,
+                                    suggestion::did_you_mean(
+                                        &variant_ident.to_string(),
+                                        &variant_names
+                                    ),
+                                ),
+                            ));
+                        }
+                    }
+                    None => {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "the container-level `default` directive of an enum requires `variant` (e.g., `default(variant = \"Name\")`)",
+                        ));
+                    }
+                }
+            }
+        }
+
+        let has_debug = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::DebugImpl));
+        for variant in &variants {
+            for field in &variant.fields {
+                for directive in field.directives.iter() {
+                    if let DirectiveKind::Debug(_) = directive.kind
+                        && !has_debug
+                    {
+                        errors.push(syn::Error::new(
+                            directive.span(),
+                            "the `debug` directive of a field requires the container-level `debug_impl` directive",
+                        ));
+                    }
+                }
+            }
+        }
+
+        let has_display = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::Display(_)));
+        for variant in &mut variants {
+            check_variant_display(variant, has_display, &mut errors);
+        }
+
+        errors.finish()?;
+
         Ok(SynItemEnum {
-            variants: item_enum
-                .variants
-                .iter_mut()
-                .map(SynVariant::parse)
-                .collect::<Result<Vec<_>, _>>()?,
+            directives: container_directives,
+            variants,
             item_enum,
+            args: args.clone(),
         })
     }
 }
@@ -33,6 +201,10 @@ impl quote::ToTokens for SynItemEnum {
         });
 
         for variant in &self.variants {
+            for directive in variant.directives.iter() {
+                variant_directive_to_tokens(&self.args, item_enum, variant, directive, tokens);
+            }
+
             for field in &variant.fields {
                 for directive in field.directives.iter() {
                     directive_to_tokens(
@@ -46,6 +218,34 @@ impl quote::ToTokens for SynItemEnum {
                 }
             }
         }
+
+        for directive in self.directives.iter() {
+            if let DirectiveKind::DefaultImpl(params) = &directive.kind
+                && let Some(variant_ident) = &params.variant
+                && let Some(variant) = self
+                    .variants
+                    .iter()
+                    .find(|variant| variant.variant.ident == *variant_ident)
+            {
+                default_impl_to_tokens(item_enum, variant, tokens);
+            }
+
+            if let DirectiveKind::Display(_) = directive.kind {
+                display_to_tokens(item_enum, &self.variants, tokens);
+            }
+
+            if let DirectiveKind::ErrorImpl = directive.kind {
+                error_to_tokens(item_enum, &self.variants, tokens);
+            }
+
+            if let DirectiveKind::DebugImpl = directive.kind {
+                debug::enum_debug_to_tokens(item_enum, &self.variants, tokens);
+            }
+
+            if let DirectiveKind::Kind(params) = &directive.kind {
+                kind_to_tokens(&self.args, params, item_enum, &self.variants, tokens);
+            }
+        }
     }
 }
 
@@ -61,8 +261,21 @@ fn directive_to_tokens(
     let generic_idents = create_generic_idents(&item_enum.generics);
 
     match &directive.kind {
-        DirectiveKind::From => {
+        DirectiveKind::From(params) => {
             from_to_tokens(
+                params,
+                &generics_for_impl,
+                &generic_idents,
+                item_enum,
+                variant,
+                field,
+                field_index,
+                tokens,
+            );
+        }
+        DirectiveKind::Into(params) => {
+            into_to_tokens(
+                params,
                 &generics_for_impl,
                 &generic_idents,
                 item_enum,
@@ -72,8 +285,21 @@ fn directive_to_tokens(
                 tokens,
             );
         }
-        DirectiveKind::Convert => {
+        DirectiveKind::Convert(params) => {
             from_to_tokens(
+                params,
+                &generics_for_impl,
+                &generic_idents,
+                item_enum,
+                variant,
+                field,
+                field_index,
+                tokens,
+            );
+            into_to_tokens(
+                &IntoParams {
+                    boxed: params.boxed,
+                },
                 &generics_for_impl,
                 &generic_idents,
                 item_enum,
@@ -83,32 +309,523 @@ fn directive_to_tokens(
                 tokens,
             );
         }
-        DirectiveKind::Into
-        | DirectiveKind::GetRef(_)
+        DirectiveKind::GetRef(_)
         | DirectiveKind::GetCopy(_)
         | DirectiveKind::GetClone(_)
         | DirectiveKind::GetMut(_)
         | DirectiveKind::Access(_)
+        | DirectiveKind::Set(_)
+        | DirectiveKind::With(_)
+        | DirectiveKind::Builder(_)
+        | DirectiveKind::BuilderField(_)
+        | DirectiveKind::New(_)
+        | DirectiveKind::DefaultImpl(_)
+        | DirectiveKind::ErrorImpl
+        | DirectiveKind::DebugImpl
+        | DirectiveKind::Key(_)
+        | DirectiveKind::Is(_)
+        | DirectiveKind::AsVariant(_)
+        | DirectiveKind::AsVariantMut(_)
+        | DirectiveKind::IntoVariant(_)
+        | DirectiveKind::Kind(_)
         | DirectiveKind::AsRef
         | DirectiveKind::AsMut
         | DirectiveKind::As
         | DirectiveKind::Deref
         | DirectiveKind::DerefMut => {
-            panic!(
-                "unsupported directive for enum, directive = {}",
+            unreachable!(
+                "directive `{}` is rejected on enum variant fields during parsing",
                 directive.kind
             );
         }
+        DirectiveKind::Default(_) => {
+            // the value is used by the `from` directives of the other fields
+        }
+        DirectiveKind::Skip(_) => {
+            // the container-level directives are already applied to the fields
+        }
+        DirectiveKind::Display(_) => {
+            // `display(forward)` is used by the `Display` implementation of the enum
+        }
+        DirectiveKind::Source => {
+            // the field is returned by the `Error` implementation of the enum
+        }
+        DirectiveKind::Debug(_) => {
+            // the params are used by the `Debug` implementation of the enum
+        }
     }
 }
 
+fn variant_directive_to_tokens(
+    args: &Args,
+    item_enum: &syn::ItemEnum,
+    variant: &SynVariant,
+    directive: &Directive,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let variant_ident = &variant.variant.ident;
+    let where_clause = item_enum.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+
+    let params = match &directive.kind {
+        DirectiveKind::Is(params)
+        | DirectiveKind::AsVariant(params)
+        | DirectiveKind::AsVariantMut(params)
+        | DirectiveKind::IntoVariant(params) => params,
+        DirectiveKind::Display(_) => {
+            // the format string is used by the `Display` implementation of the enum
+            return;
+        }
+        DirectiveKind::Skip(_) => {
+            // the container-level directives are already applied to the variant
+            return;
+        }
+        DirectiveKind::From(_) => {
+            // only unit variants are accepted
+            tokens.extend(quote! {
+                impl #generics_for_impl ::core::convert::From<()> for #ident #generic_idents
+                #where_clause {
+                    fn from(_: ()) -> Self {
+                        Self::#variant_ident {}
+                    }
+                }
+
+                impl #generics_for_impl ::core::convert::From<()> for ::std::boxed::Box<#ident #generic_idents>
+                #where_clause {
+                    fn from(value: ()) -> Self {
+                        ::std::boxed::Box::new(#ident::from(value))
+                    }
+                }
+            });
+            return;
+        }
+        _ => {
+            unreachable!(
+                "directive `{}` is rejected on enum variants during parsing",
+                directive.kind
+            );
+        }
+    };
+    let fn_name = &params.name;
+    let vis = params
+        .vis
+        .as_ref()
+        .unwrap_or(args.vis())
+        .resolve_with(&item_enum.vis);
+
+    let reference = match &directive.kind {
+        DirectiveKind::AsVariant(_) => quote! { & },
+        DirectiveKind::AsVariantMut(_) => quote! { &mut },
+        DirectiveKind::IntoVariant(_) => quote! {},
+        _ => {
+            tokens.extend(quote! {
+                impl #generics_for_impl #ident #generic_idents
+                #where_clause {
+                    #vis fn #fn_name(&self) -> bool {
+                        ::core::matches!(self, Self::#variant_ident { .. })
+                    }
+                }
+            });
+            return;
+        }
+    };
+
+    // a single field is returned by itself, multiple fields are returned as a tuple
+    let members = variant
+        .fields
+        .iter()
+        .map(SynField::member)
+        .collect::<Vec<_>>();
+    let bindings = members
+        .iter()
+        .map(|member| match member {
+            syn::Member::Named(ident) => quote::format_ident!("__self_{}", ident),
+            syn::Member::Unnamed(index) => quote::format_ident!("__self_{}", index.index),
+        })
+        .collect::<Vec<_>>();
+    let field_types = variant.fields.iter().map(|field| &field.field.ty);
+    let (value_type, value) = match bindings.as_slice() {
+        [binding] => {
+            let field_type = &variant.fields[0].field.ty;
+            (quote! { #reference #field_type }, quote! { #binding })
+        }
+        _ => (
+            quote! { (#(#reference #field_types),*) },
+            quote! { (#(#bindings),*) },
+        ),
+    };
+    let pattern = quote! { Self::#variant_ident { #(#members: #bindings),* } };
+
+    tokens.extend(match &directive.kind {
+        DirectiveKind::AsVariant(_) => quote! {
+            impl #generics_for_impl #ident #generic_idents
+            #where_clause {
+                #vis fn #fn_name(&self) -> ::core::option::Option<#value_type> {
+                    match self {
+                        #pattern => ::core::option::Option::Some(#value),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        },
+        DirectiveKind::AsVariantMut(_) => quote! {
+            impl #generics_for_impl #ident #generic_idents
+            #where_clause {
+                #vis fn #fn_name(&mut self) -> ::core::option::Option<#value_type> {
+                    match self {
+                        #pattern => ::core::option::Option::Some(#value),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        },
+        _ => quote! {
+            impl #generics_for_impl #ident #generic_idents
+            #where_clause {
+                #vis fn #fn_name(self) -> ::core::result::Result<#value_type, Self> {
+                    match self {
+                        #pattern => ::core::result::Result::Ok(#value),
+                        #[allow(unreachable_patterns)]
+                        value => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+        },
+    });
+}
+
+/// Checks the `display` directives of the variant and of its fields, and stores the format string.
+fn check_variant_display(variant: &mut SynVariant, has_display: bool, errors: &mut Errors) {
+    let mut has_format = false;
+    for directive in variant.directives.iter() {
+        if let DirectiveKind::Display(DisplayParams::Format(format)) = &directive.kind {
+            has_format = true;
+            if !has_display {
+                errors.push(syn::Error::new(
+                    directive.span(),
+                    "the `display` directive of a variant requires the container-level `display` directive",
+                ));
+            } else {
+                variant.display_format = errors.handle(FieldFormat::parse(format, &variant.fields));
+            }
+        }
+    }
+
+    let mut has_forward = false;
+    for field in &variant.fields {
+        for directive in field.directives.iter() {
+            if let DirectiveKind::Display(_) = directive.kind {
+                if !has_display {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        "`display(forward)` requires the container-level `display` directive",
+                    ));
+                } else if has_format {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        "`display(forward)` cannot be used together with the format string of the variant",
+                    ));
+                } else if has_forward {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        "`display(forward)` can be specified only for one field of a variant",
+                    ));
+                }
+                has_forward = true;
+            }
+        }
+    }
+
+    if has_display && !has_format && !has_forward && !variant.fields.is_empty() {
+        errors.push(syn::Error::new(
+            variant.variant.ident.span(),
+            format!(
+                "variant `{}` requires a format string (e.g., `#[attrimpl(display(\"...\"))]`) or a field with `display(forward)`",
+                variant.variant.ident,
+            ),
+        ));
+    }
+}
+
+/// Implements `Display`, a variant is formatted by its format string, by its field marked by
+/// `display(forward)`, or by its name if it has no fields.
+fn display_to_tokens<'a>(
+    item_enum: &syn::ItemEnum,
+    variants: &'a [SynVariant],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let forward_field = |variant: &'a SynVariant| {
+        variant.fields.iter().find(|field| {
+            field
+                .directives
+                .iter()
+                .any(|directive| matches!(directive.kind, DirectiveKind::Display(_)))
+        })
+    };
+
+    let ident = &item_enum.ident;
+    let mut generics = item_enum.generics.clone();
+    generics.where_clause = create_where_clause_with_bound(
+        &generics,
+        variants
+            .iter()
+            .filter(|variant| variant.display_format.is_none())
+            .filter_map(forward_field)
+            .map(|field| &field.field.ty),
+        &quote! { ::core::fmt::Display },
+    );
+    for display_format in variants
+        .iter()
+        .filter_map(|variant| variant.display_format.as_ref())
+    {
+        generics.where_clause = display_format.where_clause(&generics);
+    }
+    let where_clause = &generics.where_clause;
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+
+    let binding = |member: &syn::Member| match member {
+        syn::Member::Named(ident) => quote::format_ident!("__self_{}", ident),
+        syn::Member::Unnamed(index) => quote::format_ident!("__self_{}", index.index),
+    };
+
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.variant.ident;
+        let forward_field = forward_field(variant);
+
+        if let Some(display_format) = &variant.display_format {
+            let format = &display_format.format;
+            let members = display_format.args.iter().map(|(member, _)| member);
+            let bindings = display_format
+                .args
+                .iter()
+                .map(|(member, _)| binding(member))
+                .collect::<Vec<_>>();
+            let arg_idents = display_format.args.iter().map(|(_, arg_ident)| arg_ident);
+            quote! {
+                Self::#variant_ident { #(#members: #bindings,)* .. } => {
+                    ::core::write!(f, #format, #(#arg_idents = #bindings),*)
+                }
+            }
+        } else if let Some(field) = forward_field {
+            let member = field.member();
+            let binding = binding(&member);
+            quote! {
+                Self::#variant_ident { #member: #binding, .. } => {
+                    ::core::fmt::Display::fmt(#binding, f)
+                }
+            }
+        } else {
+            let name = variant_ident.to_string();
+            quote! {
+                Self::#variant_ident { .. } => f.write_str(#name),
+            }
+        }
+    });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::fmt::Display for #ident #generic_idents
+        #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    });
+}
+
+/// Implements `std::error::Error`, `source()` returns the source field of the variant if it has one.
+fn error_to_tokens(
+    item_enum: &syn::ItemEnum,
+    variants: &[SynVariant],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let where_clause = item_enum.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+
+    // the trait is generated only if a variant has a source, otherwise it would be unused
+    let as_dyn_error = variants
+        .iter()
+        .any(|variant| variant.error_source.is_some())
+        .then(as_dyn_error_tokens);
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.variant.ident;
+
+        match variant.error_source {
+            Some(index) => {
+                let member = variant.fields[index].member();
+                quote! {
+                    Self::#variant_ident { #member: source, .. } => {
+                        ::core::option::Option::Some(source.__as_dyn_error())
+                    }
+                }
+            }
+            None => quote! {
+                Self::#variant_ident { .. } => ::core::option::Option::None,
+            },
+        }
+    });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::std::error::Error for #ident #generic_idents
+        #where_clause {
+            fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                #as_dyn_error
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    });
+}
+
+/// Generates the fieldless `<Enum>Kind` enum with `Display` and `FromStr` by the names of the
+/// variants, its error type `<Enum>KindParseError`, `kind()` and `From<&Enum>` for the kind.
+fn kind_to_tokens(
+    args: &Args,
+    params: &KindParams,
+    item_enum: &syn::ItemEnum,
+    variants: &[SynVariant],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let item_vis = &item_enum.vis;
+    let where_clause = item_enum.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+    let vis = params
+        .vis
+        .as_ref()
+        .unwrap_or(args.vis())
+        .resolve_with(item_vis);
+
+    let kind_ident = params
+        .name
+        .clone()
+        .unwrap_or_else(|| quote::format_ident!("{}Kind", ident));
+    let error_ident = quote::format_ident!("{}ParseError", kind_ident);
+    let derives = KindParams::DERIVES
+        .iter()
+        .map(|derive| syn::Ident::new(derive, proc_macro2::Span::call_site()));
+    let extra_derives = &params.derives;
+
+    let variant_idents = variants
+        .iter()
+        .map(|variant| &variant.variant.ident)
+        .collect::<Vec<_>>();
+    let variant_names = variant_idents
+        .iter()
+        .map(|variant_ident| variant_ident.to_string())
+        .collect::<Vec<_>>();
+    let error_message = format!("unknown `{}` variant `{{}}`", kind_ident);
+
+    tokens.extend(quote! {
+        #[derive(#(#derives,)* #(#extra_derives,)*)]
+        #item_vis enum #kind_ident {
+            #(#variant_idents,)*
+        }
+
+        impl ::core::fmt::Display for #kind_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(Self::#variant_idents => f.write_str(#variant_names),)*
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for #kind_ident {
+            type Err = #error_ident;
+
+            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                match value {
+                    #(#variant_names => ::core::result::Result::Ok(Self::#variant_idents),)*
+                    _ => ::core::result::Result::Err(#error_ident {
+                        value: ::std::string::ToString::to_string(value),
+                    }),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #item_vis struct #error_ident {
+            value: ::std::string::String,
+        }
+
+        impl #error_ident {
+            /// the string that is not the name of a variant
+            #vis fn value(&self) -> &str {
+                &self.value
+            }
+        }
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, #error_message, self.value)
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #vis fn kind(&self) -> #kind_ident {
+                match self {
+                    #(Self::#variant_idents { .. } => #kind_ident::#variant_idents,)*
+                }
+            }
+        }
+
+        impl #generics_for_impl ::core::convert::From<&#ident #generic_idents> for #kind_ident
+        #where_clause {
+            fn from(value: &#ident #generic_idents) -> Self {
+                value.kind()
+            }
+        }
+    });
+}
+
+/// Implements `Default`, the value is the given variant, its fields are set to the value of their
+/// `default` directive or to `Default::default()`.
+fn default_impl_to_tokens(
+    item_enum: &syn::ItemEnum,
+    variant: &SynVariant,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let variant_ident = &variant.variant.ident;
+    let where_clause = item_enum.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_enum.generics);
+    let generic_idents = create_generic_idents(&item_enum.generics);
+    let field_values = SynField::default_field_values(&variant.fields);
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::default::Default for #ident #generic_idents
+        #where_clause {
+            fn default() -> Self {
+                Self::#variant_ident {
+                    #field_values
+                }
+            }
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
 fn from_to_tokens(
+    params: &FromParams,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_enum: &syn::ItemEnum,
     variant: &SynVariant,
     field: &syn::Field,
-    _field_index: usize,
+    field_index: usize,
     tokens: &mut proc_macro2::TokenStream,
 ) {
     let ident = &item_enum.ident;
@@ -116,6 +833,24 @@ fn from_to_tokens(
     let where_clause = item_enum.generics.where_clause.as_ref();
     let field_type = &field.ty;
 
+    let rest = match params.defaults {
+        FromDefaults::None | FromDefaults::Container => quote! {},
+        FromDefaults::Field => variant
+            .fields
+            .iter()
+            .filter(|other| other.index != field_index)
+            .map(|other| {
+                let value = other.default_value_tokens();
+                if let Some(other_ident) = &other.field.ident {
+                    quote! { #other_ident: #value, }
+                } else {
+                    let other_index = syn::Index::from(other.index);
+                    quote! { #other_index: #value, }
+                }
+            })
+            .collect::<proc_macro2::TokenStream>(),
+    };
+
     tokens.extend(if let Some(field_ident) = &field.ident {
         // it is a struct with named fields
         quote! {
@@ -124,11 +859,12 @@ fn from_to_tokens(
                 fn from(value: #field_type) -> Self {
                     Self::#variant_ident {
                         #field_ident: value,
+                        #rest
                     }
                 }
             }
         }
-    } else {
+    } else if variant.fields.len() == 1 {
         // it is a tuple struct
         quote! {
             impl #generics_for_impl ::core::convert::From<#field_type> for #ident #generic_idents
@@ -138,6 +874,20 @@ fn from_to_tokens(
                 }
             }
         }
+    } else {
+        // it is a tuple struct with multiple fields, the fields are initialized by their indices
+        let field_index = syn::Index::from(field_index);
+        quote! {
+            impl #generics_for_impl ::core::convert::From<#field_type> for #ident #generic_idents
+            #where_clause {
+                fn from(value: #field_type) -> Self {
+                    Self::#variant_ident {
+                        #field_index: value,
+                        #rest
+                    }
+                }
+            }
+        }
     });
 
     tokens.extend(quote! {
@@ -148,4 +898,95 @@ fn from_to_tokens(
             }
         }
     });
+
+    if params.boxed {
+        tokens.extend(quote! {
+            impl #generics_for_impl ::core::convert::From<::std::boxed::Box<#field_type>> for #ident #generic_idents
+            #where_clause {
+                fn from(value: ::std::boxed::Box<#field_type>) -> Self {
+                    <Self as ::core::convert::From<#field_type>>::from(*value)
+                }
+            }
+
+            impl #generics_for_impl ::core::convert::From<::std::boxed::Box<#field_type>> for ::std::boxed::Box<#ident #generic_idents>
+            #where_clause {
+                fn from(value: ::std::boxed::Box<#field_type>) -> Self {
+                    ::std::boxed::Box::new(#ident::from(*value))
+                }
+            }
+        });
+    }
+}
+
+/// Implements `TryFrom<Enum>` and `TryFrom<&Enum>` for the field type (and its reference), the error
+/// is the original value if it is another variant.
+#[allow(clippy::too_many_arguments)]
+fn into_to_tokens(
+    params: &IntoParams,
+    generics_for_impl: &syn::Generics,
+    generic_idents: &syn::Generics,
+    item_enum: &syn::ItemEnum,
+    variant: &SynVariant,
+    field: &syn::Field,
+    field_index: usize,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_enum.ident;
+    let variant_ident = &variant.variant.ident;
+    let where_clause = item_enum.generics.where_clause.as_ref();
+    let field_type = &field.ty;
+    let member = match &field.ident {
+        Some(field_ident) => syn::Member::Named(field_ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(field_index)),
+    };
+
+    let mut generics_for_ref_impl = generics_for_impl.clone();
+    generics_for_ref_impl
+        .params
+        .insert(0, syn::parse_quote! { '__a });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::convert::TryFrom<#ident #generic_idents> for #field_type
+        #where_clause {
+            type Error = #ident #generic_idents;
+
+            fn try_from(value: #ident #generic_idents) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #ident::#variant_ident { #member: value, .. } => ::core::result::Result::Ok(value),
+                    #[allow(unreachable_patterns)]
+                    value => ::core::result::Result::Err(value),
+                }
+            }
+        }
+
+        impl #generics_for_ref_impl ::core::convert::TryFrom<&'__a #ident #generic_idents> for &'__a #field_type
+        #where_clause {
+            type Error = &'__a #ident #generic_idents;
+
+            fn try_from(value: &'__a #ident #generic_idents) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #ident::#variant_ident { #member: value, .. } => ::core::result::Result::Ok(value),
+                    #[allow(unreachable_patterns)]
+                    value => ::core::result::Result::Err(value),
+                }
+            }
+        }
+    });
+
+    if params.boxed {
+        tokens.extend(quote! {
+            impl #generics_for_impl ::core::convert::TryFrom<::std::boxed::Box<#ident #generic_idents>> for #field_type
+            #where_clause {
+                type Error = ::std::boxed::Box<#ident #generic_idents>;
+
+                fn try_from(value: ::std::boxed::Box<#ident #generic_idents>) -> ::core::result::Result<Self, Self::Error> {
+                    match *value {
+                        #ident::#variant_ident { #member: value, .. } => ::core::result::Result::Ok(value),
+                        #[allow(unreachable_patterns)]
+                        value => ::core::result::Result::Err(::std::boxed::Box::new(value)),
+                    }
+                }
+            }
+        });
+    }
 }
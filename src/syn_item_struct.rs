@@ -1,22 +1,214 @@
-use quote::quote;
+use quote::{ToTokens, quote};
 
 use crate::{
     access_params::GetRefType,
-    create_generic_idents, create_generics_for_impl,
-    directive::{Directive, DirectiveKind},
+    args::Args,
+    as_dyn_error_tokens, builder, create_generic_idents, create_generics_for_impl,
+    create_where_clause_with_bound, debug,
+    directive::{Directive, DirectiveKind, DirectiveScope},
+    directives::Directives,
+    display_params::DisplayParams,
+    errors::Errors,
+    field_format::FieldFormat,
+    from_params::{FromDefaults, FromParams},
+    into_params::IntoParams,
+    key_params::KeyParams,
+    new_params::NewParams,
+    set_params::{SetParams, SetReturn},
     syn_field::SynField,
+    with_params::WithParams,
 };
 
 pub struct SynItemStruct {
     item_struct: syn::ItemStruct,
+    /// container-level directives, the ones applied to the fields are also stored by the fields
+    directives: Directives,
     fields: Vec<SynField>,
+    /// the checked format string of the container-level `display` directive
+    display_format: Option<FieldFormat>,
+    /// index of the field returned by `Error::source()`
+    error_source: Option<usize>,
+    args: Args,
 }
 
 impl SynItemStruct {
-    pub fn parse(mut item_struct: syn::ItemStruct) -> syn::Result<Self> {
+    pub fn parse(mut item_struct: syn::ItemStruct, args: &Args) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+
+        let container_directives = Directives::parse_container_attributes(
+            &mut item_struct.attrs,
+            item_struct.ident.clone(),
+            DirectiveScope::Struct,
+            &mut errors,
+        );
+
+        let mut fields = SynField::parse(
+            &mut item_struct.fields,
+            DirectiveScope::StructField,
+            &mut errors,
+        );
+
+        if let syn::Fields::Named(_) = item_struct.fields {
+            for field in &mut fields {
+                field.apply_container_directives(
+                    &container_directives,
+                    DirectiveScope::StructField,
+                    &mut errors,
+                );
+            }
+        } else {
+            for directive in container_directives.iter() {
+                // these directives support tuple and unit structs as well
+                if let DirectiveKind::New(_)
+                | DirectiveKind::DefaultImpl(_)
+                | DirectiveKind::Display(_)
+                | DirectiveKind::ErrorImpl
+                | DirectiveKind::DebugImpl = directive.kind
+                {
+                    continue;
+                }
+
+                errors.push(syn::Error::new(
+                    directive.span(),
+                    format!(
+                        "the container-level `{}` directive requires a struct with named fields",
+                        directive.kind,
+                    ),
+                ));
+            }
+        }
+
+        for directive in container_directives.iter() {
+            if let DirectiveKind::DefaultImpl(params) = &directive.kind
+                && let Some(variant) = &params.variant
+            {
+                errors.push(syn::Error::new(
+                    variant.span(),
+                    "`variant` can be specified only for enums",
+                ));
+            }
+        }
+
+        let mut display_directive = None;
+        let mut display_format = None;
+        for directive in container_directives.iter() {
+            if let DirectiveKind::Display(params) = &directive.kind {
+                display_directive = Some(directive);
+                if let DisplayParams::Format(format) = params {
+                    display_format = errors.handle(FieldFormat::parse(format, &fields));
+                }
+            }
+        }
+        let mut has_display_forward = false;
+        for field in &fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::Display(DisplayParams::Forward) = directive.kind {
+                    match display_directive.map(|directive| &directive.kind) {
+                        None => errors.push(syn::Error::new(
+                            directive.span(),
+                            "`display(forward)` requires the container-level `display` directive",
+                        )),
+                        Some(DirectiveKind::Display(DisplayParams::Format(_))) => {
+                            errors.push(syn::Error::new(
+                                directive.span(),
+                                "`display(forward)` cannot be used together with the format string of the struct",
+                            ))
+                        }
+                        Some(_) if has_display_forward => errors.push(syn::Error::new(
+                            directive.span(),
+                            "`display(forward)` can be specified only for one field",
+                        )),
+                        Some(_) => {}
+                    }
+                    has_display_forward = true;
+                }
+            }
+        }
+        if let Some(directive) = display_directive
+            && let DirectiveKind::Display(DisplayParams::Delegated) = directive.kind
+            && !has_display_forward
+        {
+            errors.push(syn::Error::new(
+                directive.span(),
+                "the `display` directive of a struct requires a format string (e.g., `display(\"{name} ({id})\")`) or a field with `display(forward)`",
+            ));
+        }
+
+        let has_error = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::ErrorImpl));
+        let error_source = SynField::error_source(&fields, has_error, &mut errors);
+
+        let has_debug = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::DebugImpl));
+        for field in &fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::Debug(_) = directive.kind
+                    && !has_debug
+                {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        "the `debug` directive of a field requires the container-level `debug_impl` directive",
+                    ));
+                }
+            }
+        }
+
+        Directives::check_method_names(
+            fields.iter().map(|field| {
+                (
+                    field.member().to_token_stream().to_string(),
+                    &field.directives,
+                )
+            }),
+            &mut errors,
+        );
+        check_key_fields(&item_struct, &fields, &mut errors);
+
+        let has_builder = container_directives
+            .iter()
+            .any(|directive| matches!(directive.kind, DirectiveKind::Builder(_)));
+        for field in &fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::BuilderField(_) = directive.kind
+                    && !has_builder
+                {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        "the `builder` directive of a field requires the container-level `builder` directive",
+                    ));
+                }
+            }
+        }
+
+        for field in &fields {
+            for directive in field.directives.iter() {
+                if let DirectiveKind::From(params) | DirectiveKind::Convert(params) =
+                    &directive.kind
+                    && params.defaults == FromDefaults::None
+                    && fields.len() > 1
+                {
+                    errors.push(syn::Error::new(
+                        directive.span(),
+                        format!(
+                            "`{}` on a struct with multiple fields requires `field_default` or `container_default`",
+                            directive.kind,
+                        ),
+                    ));
+                }
+            }
+        }
+
+        errors.finish()?;
+
         Ok(SynItemStruct {
-            fields: SynField::parse(&mut item_struct.fields)?,
+            directives: container_directives,
+            fields,
+            display_format,
+            error_source,
             item_struct,
+            args: args.clone(),
         })
     }
 }
@@ -31,14 +223,77 @@ impl quote::ToTokens for SynItemStruct {
 
         for field in &self.fields {
             for directive in field.directives.iter() {
-                directive_to_tokens(item_struct, &field.field, field.index, directive, tokens);
+                directive_to_tokens(
+                    &self.args,
+                    item_struct,
+                    &self.fields,
+                    &field.field,
+                    field.index,
+                    directive,
+                    tokens,
+                );
+            }
+        }
+
+        key_to_tokens(item_struct, &self.fields, tokens);
+
+        for directive in self.directives.iter() {
+            match &directive.kind {
+                DirectiveKind::Builder(params) => {
+                    builder::builder_to_tokens(
+                        &self.args,
+                        params,
+                        item_struct,
+                        &self.fields,
+                        tokens,
+                    );
+                }
+                DirectiveKind::New(params) => {
+                    new_to_tokens(
+                        params,
+                        params
+                            .vis
+                            .as_ref()
+                            .unwrap_or(self.args.vis())
+                            .resolve_with(&item_struct.vis),
+                        item_struct,
+                        &self.fields,
+                        tokens,
+                    );
+                }
+                DirectiveKind::DefaultImpl(_) => {
+                    default_impl_to_tokens(item_struct, &self.fields, tokens);
+                }
+                DirectiveKind::Display(_) => {
+                    if let Some(display_format) = &self.display_format {
+                        display_to_tokens(display_format, item_struct, tokens);
+                    } else if let Some(field) = self.fields.iter().find(|field| {
+                        field
+                            .directives
+                            .iter()
+                            .any(|directive| matches!(directive.kind, DirectiveKind::Display(_)))
+                    }) {
+                        display_forward_to_tokens(item_struct, field, tokens);
+                    }
+                }
+                DirectiveKind::ErrorImpl => {
+                    error_to_tokens(item_struct, &self.fields, self.error_source, tokens);
+                }
+                DirectiveKind::DebugImpl => {
+                    debug::struct_debug_to_tokens(item_struct, &self.fields, tokens);
+                }
+                _ => {
+                    // the directives applied to the fields are generated by the fields
+                }
             }
         }
     }
 }
 
 fn directive_to_tokens(
+    args: &Args,
     item_struct: &syn::ItemStruct,
+    fields: &[SynField],
     field: &syn::Field,
     field_index: usize,
     directive: &Directive,
@@ -48,18 +303,21 @@ fn directive_to_tokens(
     let generic_idents = create_generic_idents(&item_struct.generics);
 
     match &directive.kind {
-        DirectiveKind::From => {
+        DirectiveKind::From(params) => {
             from_to_tokens(
+                params,
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
+                fields,
                 field,
                 field_index,
                 tokens,
             );
         }
-        DirectiveKind::Into => {
+        DirectiveKind::Into(params) => {
             into_to_tokens(
+                params,
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -68,16 +326,21 @@ fn directive_to_tokens(
                 tokens,
             );
         }
-        DirectiveKind::Convert => {
+        DirectiveKind::Convert(params) => {
             from_to_tokens(
+                params,
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
+                fields,
                 field,
                 field_index,
                 tokens,
             );
             into_to_tokens(
+                &IntoParams {
+                    boxed: params.boxed,
+                },
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -155,6 +418,7 @@ fn directive_to_tokens(
         DirectiveKind::GetRef(params) => {
             get_ref_to_tokens(
                 &params.name,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -166,6 +430,7 @@ fn directive_to_tokens(
         DirectiveKind::GetCopy(params) => {
             get_copy_to_tokens(
                 &params.name,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -177,6 +442,7 @@ fn directive_to_tokens(
         DirectiveKind::GetClone(params) => {
             get_clone_to_tokens(
                 &params.name,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -188,6 +454,7 @@ fn directive_to_tokens(
         DirectiveKind::GetMut(params) => {
             get_mut_to_tokens(
                 &params.name,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -200,6 +467,7 @@ fn directive_to_tokens(
             match params.get_ref_type {
                 GetRefType::Ref => get_ref_to_tokens(
                     &params.get_name,
+                    params.get_vis.as_ref().unwrap_or(args.vis()).resolve(field),
                     &generics_for_impl,
                     &generic_idents,
                     item_struct,
@@ -209,6 +477,7 @@ fn directive_to_tokens(
                 ),
                 GetRefType::Clone => get_clone_to_tokens(
                     &params.get_name,
+                    params.get_vis.as_ref().unwrap_or(args.vis()).resolve(field),
                     &generics_for_impl,
                     &generic_idents,
                     item_struct,
@@ -218,6 +487,7 @@ fn directive_to_tokens(
                 ),
                 GetRefType::Copy => get_copy_to_tokens(
                     &params.get_name,
+                    params.get_vis.as_ref().unwrap_or(args.vis()).resolve(field),
                     &generics_for_impl,
                     &generic_idents,
                     item_struct,
@@ -229,6 +499,7 @@ fn directive_to_tokens(
 
             get_mut_to_tokens(
                 &params.get_mut_name,
+                params.mut_vis.as_ref().unwrap_or(args.vis()).resolve(field),
                 &generics_for_impl,
                 &generic_idents,
                 item_struct,
@@ -236,22 +507,440 @@ fn directive_to_tokens(
                 field_index,
                 tokens,
             );
+
+            if let Some(set_name) = &params.set_name {
+                set_to_tokens(
+                    &SetParams {
+                        name: set_name.clone(),
+                        into: false,
+                        returns: SetReturn::Unit,
+                        vis: None,
+                    },
+                    params.set_vis.as_ref().unwrap_or(args.vis()).resolve(field),
+                    &generics_for_impl,
+                    &generic_idents,
+                    item_struct,
+                    field,
+                    field_index,
+                    tokens,
+                );
+            }
+        }
+        DirectiveKind::Set(params) => {
+            set_to_tokens(
+                params,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
+                &generics_for_impl,
+                &generic_idents,
+                item_struct,
+                field,
+                field_index,
+                tokens,
+            );
+        }
+        DirectiveKind::With(params) => {
+            with_to_tokens(
+                params,
+                params.vis.as_ref().unwrap_or(args.vis()).resolve(field),
+                &generics_for_impl,
+                &generic_idents,
+                item_struct,
+                field,
+                field_index,
+                tokens,
+            );
+        }
+        DirectiveKind::Default(_) => {
+            // the value is used by the `from` directives of the other fields
+        }
+        DirectiveKind::Skip(_) => {
+            // the container-level directives are already applied to the fields
+        }
+        DirectiveKind::Builder(_)
+        | DirectiveKind::New(_)
+        | DirectiveKind::DefaultImpl(_)
+        | DirectiveKind::ErrorImpl
+        | DirectiveKind::DebugImpl => {
+            unreachable!(
+                "directive `{}` is only accepted on the struct itself",
+                directive.kind
+            );
+        }
+        DirectiveKind::BuilderField(_) => {
+            // the params are used by the builder of the struct
+        }
+        DirectiveKind::Display(_) => {
+            // the field with `display(forward)` is used by the container-level `display` directive
+        }
+        DirectiveKind::Source => {
+            // the field is returned by the `Error` implementation of the struct
+        }
+        DirectiveKind::Debug(_) => {
+            // the params are used by the `Debug` implementation of the struct
+        }
+        DirectiveKind::Key(_) => {
+            // the key fields are compared together by `key_to_tokens`
+        }
+        DirectiveKind::Is(_)
+        | DirectiveKind::AsVariant(_)
+        | DirectiveKind::AsVariantMut(_)
+        | DirectiveKind::IntoVariant(_)
+        | DirectiveKind::Kind(_) => {
+            unreachable!(
+                "directive `{}` is rejected on struct fields during parsing",
+                directive.kind
+            );
+        }
+    }
+}
+
+/// the traits implemented by the `key` directive
+const KEY_TRAITS: &[&str] = &["PartialEq", "Eq", "Hash", "PartialOrd", "Ord"];
+
+fn key_params(field: &SynField) -> Option<&KeyParams> {
+    field
+        .directives
+        .iter()
+        .find_map(|directive| match &directive.kind {
+            DirectiveKind::Key(params) => Some(params),
+            _ => None,
+        })
+}
+
+/// Checks that `borrow` is used with a single key field, and that the traits implemented by the
+/// `key` directive are not derived. The derives are visible only if they follow the attribute of
+/// the macro.
+fn check_key_fields(item_struct: &syn::ItemStruct, fields: &[SynField], errors: &mut Errors) {
+    let key_count = fields
+        .iter()
+        .filter(|field| key_params(field).is_some())
+        .count();
+    if key_count == 0 {
+        return;
+    }
+
+    for field in fields {
+        for directive in field.directives.iter() {
+            if let DirectiveKind::Key(params) = &directive.kind
+                && params.borrow
+                && key_count > 1
+            {
+                errors.push(syn::Error::new(
+                    directive.span(),
+                    "`key(borrow)` requires a single key field",
+                ));
+            }
+        }
+    }
+
+    for attr in &item_struct.attrs {
+        if !attr.path().is_ident("derive") {
+            continue;
+        }
+        // a malformed derive is reported by the compiler
+        let Ok(paths) = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+        ) else {
+            continue;
+        };
+        for path in paths {
+            if let Some(segment) = path.segments.last()
+                && KEY_TRAITS.contains(&segment.ident.to_string().as_str())
+            {
+                errors.push(syn::Error::new_spanned(
+                    &path,
+                    format!(
+                        "`{}` is implemented by the `key` directive, it cannot be derived",
+                        segment.ident,
+                    ),
+                ));
+            }
         }
     }
 }
 
+/// Implements `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` by comparing the key fields in the
+/// order of declaration, and `Borrow` if the key field has `key(borrow)`.
+fn key_to_tokens(
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let key_fields = fields
+        .iter()
+        .filter(|field| key_params(field).is_some())
+        .collect::<Vec<_>>();
+    if key_fields.is_empty() {
+        return;
+    }
+
+    let ident = &item_struct.ident;
+    let key_types = key_fields.iter().map(|field| &field.field.ty);
+    let where_clause =
+        |bound| create_where_clause_with_bound(&item_struct.generics, key_types.clone(), &bound);
+    let partial_eq_where_clause = where_clause(quote! { ::core::cmp::PartialEq });
+    let eq_where_clause = where_clause(quote! { ::core::cmp::Eq });
+    let hash_where_clause = where_clause(quote! { ::core::hash::Hash });
+    // `partial_cmp()` is implemented by `Ord::cmp()`, so `PartialOrd` requires `Ord` as well
+    let ord_where_clause = where_clause(quote! { ::core::cmp::Ord });
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+    let members = key_fields
+        .iter()
+        .map(|field| field.member())
+        .collect::<Vec<_>>();
+    let (first_member, other_members) = members.split_first().unwrap();
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::cmp::PartialEq for #ident #generic_idents
+        #partial_eq_where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #(::core::cmp::PartialEq::eq(&self.#members, &other.#members))&&*
+            }
+        }
+
+        impl #generics_for_impl ::core::cmp::Eq for #ident #generic_idents
+        #eq_where_clause {}
+
+        impl #generics_for_impl ::core::hash::Hash for #ident #generic_idents
+        #hash_where_clause {
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                #(::core::hash::Hash::hash(&self.#members, state);)*
+            }
+        }
+
+        impl #generics_for_impl ::core::cmp::PartialOrd for #ident #generic_idents
+        #ord_where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #generics_for_impl ::core::cmp::Ord for #ident #generic_idents
+        #ord_where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.#first_member, &other.#first_member)
+                    #(.then_with(|| ::core::cmp::Ord::cmp(&self.#other_members, &other.#other_members)))*
+            }
+        }
+    });
+
+    if let [field] = key_fields.as_slice()
+        && key_params(field).is_some_and(|params| params.borrow)
+    {
+        let member = field.member();
+        let field_type = &field.field.ty;
+        let where_clause = item_struct.generics.where_clause.as_ref();
+        tokens.extend(quote! {
+            impl #generics_for_impl ::core::borrow::Borrow<#field_type> for #ident #generic_idents
+            #where_clause {
+                fn borrow(&self) -> &#field_type {
+                    &self.#member
+                }
+            }
+        });
+    }
+}
+
+/// Generates `new()`, the fields without a `default` directive are the parameters of it.
+fn new_to_tokens(
+    params: &NewParams,
+    vis: &syn::Visibility,
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = item_struct.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+
+    let mut fn_params = proc_macro2::TokenStream::new();
+    let mut field_values = proc_macro2::TokenStream::new();
+
+    for field in fields {
+        let field_type = &field.field.ty;
+        let (param_ident, member) = match &field.field.ident {
+            Some(field_ident) => (field_ident.clone(), quote! { #field_ident }),
+            None => {
+                let field_index = syn::Index::from(field.index);
+                (
+                    quote::format_ident!("value_{}", field.index),
+                    quote! { #field_index },
+                )
+            }
+        };
+
+        field_values.extend(match field.explicit_default_value_tokens() {
+            Some(default_value) => quote! { #member: #default_value, },
+            None if params.into => {
+                fn_params.extend(quote! { #param_ident: impl ::core::convert::Into<#field_type>, });
+                quote! { #member: ::core::convert::Into::into(#param_ident), }
+            }
+            None if field.field.ident.is_some() => {
+                fn_params.extend(quote! { #param_ident: #field_type, });
+                quote! { #member, }
+            }
+            None => {
+                fn_params.extend(quote! { #param_ident: #field_type, });
+                quote! { #member: #param_ident, }
+            }
+        });
+    }
+
+    tokens.extend(quote! {
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #vis fn new(#fn_params) -> Self {
+                Self {
+                    #field_values
+                }
+            }
+        }
+    });
+}
+
+/// Implements `Default`, the fields are set to the value of their `default` directive or to
+/// `Default::default()`.
+fn default_impl_to_tokens(
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = item_struct.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+    let field_values = SynField::default_field_values(fields);
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::default::Default for #ident #generic_idents
+        #where_clause {
+            fn default() -> Self {
+                Self {
+                    #field_values
+                }
+            }
+        }
+    });
+}
+
+fn display_to_tokens(
+    display_format: &FieldFormat,
+    item_struct: &syn::ItemStruct,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = display_format.where_clause(&item_struct.generics);
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+    let format = &display_format.format;
+    let args = display_format
+        .args
+        .iter()
+        .map(|(member, arg_ident)| quote! { #arg_ident = &self.#member });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::fmt::Display for #ident #generic_idents
+        #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, #format, #(#args),*)
+            }
+        }
+    });
+}
+
+/// Implements `std::error::Error`, `source()` returns the source field if there is one.
+fn error_to_tokens(
+    item_struct: &syn::ItemStruct,
+    fields: &[SynField],
+    error_source: Option<usize>,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = item_struct.generics.where_clause.as_ref();
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+
+    let source_fn = error_source.map(|index| {
+        let member = fields[index].member();
+        let as_dyn_error = as_dyn_error_tokens();
+        quote! {
+            fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                #as_dyn_error
+                ::core::option::Option::Some(self.#member.__as_dyn_error())
+            }
+        }
+    });
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::std::error::Error for #ident #generic_idents
+        #where_clause {
+            #source_fn
+        }
+    });
+}
+
+/// Implements `Display` by forwarding to the field with `display(forward)`, so the format
+/// specifiers are applied to the value of the field.
+fn display_forward_to_tokens(
+    item_struct: &syn::ItemStruct,
+    field: &SynField,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = create_where_clause_with_bound(
+        &item_struct.generics,
+        [&field.field.ty],
+        &quote! { ::core::fmt::Display },
+    );
+    let generics_for_impl = create_generics_for_impl(&item_struct.generics);
+    let generic_idents = create_generic_idents(&item_struct.generics);
+    let member = field.member();
+
+    tokens.extend(quote! {
+        impl #generics_for_impl ::core::fmt::Display for #ident #generic_idents
+        #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.#member, f)
+            }
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
 fn from_to_tokens(
+    params: &FromParams,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
+    fields: &[SynField],
     field: &syn::Field,
-    _field_index: usize,
+    field_index: usize,
     tokens: &mut proc_macro2::TokenStream,
 ) {
     let ident = &item_struct.ident;
     let where_clause = item_struct.generics.where_clause.as_ref();
     let field_type = &field.ty;
 
+    let rest = match params.defaults {
+        FromDefaults::None => quote! {},
+        FromDefaults::Field => fields
+            .iter()
+            .filter(|other| other.index != field_index)
+            .map(|other| {
+                let value = other.default_value_tokens();
+                if let Some(other_ident) = &other.field.ident {
+                    quote! { #other_ident: #value, }
+                } else {
+                    let other_index = syn::Index::from(other.index);
+                    quote! { #other_index: #value, }
+                }
+            })
+            .collect::<proc_macro2::TokenStream>(),
+        FromDefaults::Container => quote! { ..::core::default::Default::default() },
+    };
+
     tokens.extend(if let Some(field_ident) = &field.ident {
         // it is a struct with named fields
         quote! {
@@ -260,11 +949,12 @@ fn from_to_tokens(
                 fn from(value: #field_type) -> Self {
                     Self {
                         #field_ident: value,
+                        #rest
                     }
                 }
             }
         }
-    } else {
+    } else if fields.len() == 1 {
         // it is a tuple struct
         quote! {
             impl #generics_for_impl ::core::convert::From<#field_type> for #ident #generic_idents
@@ -274,6 +964,20 @@ fn from_to_tokens(
                 }
             }
         }
+    } else {
+        // it is a tuple struct with multiple fields, the fields are initialized by their indices
+        let field_index = syn::Index::from(field_index);
+        quote! {
+            impl #generics_for_impl ::core::convert::From<#field_type> for #ident #generic_idents
+            #where_clause {
+                fn from(value: #field_type) -> Self {
+                    Self {
+                        #field_index: value,
+                        #rest
+                    }
+                }
+            }
+        }
     });
 
     tokens.extend(quote! {
@@ -284,9 +988,28 @@ fn from_to_tokens(
             }
         }
     });
+
+    if params.boxed {
+        tokens.extend(quote! {
+            impl #generics_for_impl ::core::convert::From<::std::boxed::Box<#field_type>> for #ident #generic_idents
+            #where_clause {
+                fn from(value: ::std::boxed::Box<#field_type>) -> Self {
+                    <Self as ::core::convert::From<#field_type>>::from(*value)
+                }
+            }
+
+            impl #generics_for_impl ::core::convert::From<::std::boxed::Box<#field_type>> for ::std::boxed::Box<#ident #generic_idents>
+            #where_clause {
+                fn from(value: ::std::boxed::Box<#field_type>) -> Self {
+                    ::std::boxed::Box::new(#ident::from(*value))
+                }
+            }
+        });
+    }
 }
 
 fn into_to_tokens(
+    params: &IntoParams,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
@@ -316,6 +1039,31 @@ fn into_to_tokens(
             }
         }
     });
+
+    if params.boxed {
+        tokens.extend(quote! {
+            impl #generics_for_impl ::core::convert::Into<::std::boxed::Box<#field_type>> for #ident #generic_idents
+            #where_clause {
+                fn into(self) -> ::std::boxed::Box<#field_type> {
+                    ::std::boxed::Box::new(self.#field_reference_name)
+                }
+            }
+
+            impl #generics_for_impl ::core::convert::Into<#field_type> for ::std::boxed::Box<#ident #generic_idents>
+            #where_clause {
+                fn into(self) -> #field_type {
+                    (*self).#field_reference_name
+                }
+            }
+
+            impl #generics_for_impl ::core::convert::Into<::std::boxed::Box<#field_type>> for ::std::boxed::Box<#ident #generic_idents>
+            #where_clause {
+                fn into(self) -> ::std::boxed::Box<#field_type> {
+                    ::std::boxed::Box::new((*self).#field_reference_name)
+                }
+            }
+        });
+    }
 }
 
 fn deref_to_tokens(
@@ -448,8 +1196,10 @@ fn as_mut_to_tokens(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_ref_to_tokens(
     fn_name: &syn::Ident,
+    vis: &syn::Visibility,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
@@ -475,15 +1225,17 @@ fn get_ref_to_tokens(
     tokens.extend(quote! {
         impl #generics_for_impl #ident #generic_idents
         #where_clause {
-            pub fn #fn_name(&self) -> &#field_type {
+            #vis fn #fn_name(&self) -> &#field_type {
                 &self.#field_reference_name
             }
         }
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_clone_to_tokens(
     fn_name: &syn::Ident,
+    vis: &syn::Visibility,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
@@ -509,15 +1261,17 @@ fn get_clone_to_tokens(
     tokens.extend(quote! {
         impl #generics_for_impl #ident #generic_idents
         #where_clause {
-            pub fn #fn_name(&self) -> #field_type {
+            #vis fn #fn_name(&self) -> #field_type {
                 ::std::clone::Clone::clone(&self.#field_reference_name)
             }
         }
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_copy_to_tokens(
     fn_name: &syn::Ident,
+    vis: &syn::Visibility,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
@@ -543,15 +1297,17 @@ fn get_copy_to_tokens(
     tokens.extend(quote! {
         impl #generics_for_impl #ident #generic_idents
         #where_clause {
-            pub fn #fn_name(&self) -> #field_type {
+            #vis fn #fn_name(&self) -> #field_type {
                 self.#field_reference_name
             }
         }
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_mut_to_tokens(
     fn_name: &syn::Ident,
+    vis: &syn::Visibility,
     generics_for_impl: &syn::Generics,
     generic_idents: &syn::Generics,
     item_struct: &syn::ItemStruct,
@@ -577,9 +1333,118 @@ fn get_mut_to_tokens(
     tokens.extend(quote! {
         impl #generics_for_impl #ident #generic_idents
         #where_clause {
-            pub fn #fn_name(&mut self) -> &mut #field_type {
+            #vis fn #fn_name(&mut self) -> &mut #field_type {
                 &mut self.#field_reference_name
             }
         }
     });
 }
+
+#[allow(clippy::too_many_arguments)]
+fn set_to_tokens(
+    params: &SetParams,
+    vis: &syn::Visibility,
+    generics_for_impl: &syn::Generics,
+    generic_idents: &syn::Generics,
+    item_struct: &syn::ItemStruct,
+    field: &syn::Field,
+    field_index: usize,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = item_struct.generics.where_clause.as_ref();
+    let field_type = &field.ty;
+    let fn_name = &params.name;
+
+    let field_reference_name = field
+        .ident
+        .as_ref()
+        .map(|ident| quote! { #ident })
+        .clone()
+        .unwrap_or_else(|| {
+            let field_index = syn::Index::from(field_index);
+            quote! { #field_index }
+        });
+
+    let (value_type, value) = if params.into {
+        (
+            quote! { impl ::core::convert::Into<#field_type> },
+            quote! { ::core::convert::Into::into(value) },
+        )
+    } else {
+        (quote! { #field_type }, quote! { value })
+    };
+
+    let method = match params.returns {
+        SetReturn::Unit => quote! {
+            #vis fn #fn_name(&mut self, value: #value_type) {
+                self.#field_reference_name = #value;
+            }
+        },
+        SetReturn::Chain => quote! {
+            #vis fn #fn_name(&mut self, value: #value_type) -> &mut Self {
+                self.#field_reference_name = #value;
+                self
+            }
+        },
+        SetReturn::Previous => quote! {
+            #vis fn #fn_name(&mut self, value: #value_type) -> #field_type {
+                ::core::mem::replace(&mut self.#field_reference_name, #value)
+            }
+        },
+    };
+
+    tokens.extend(quote! {
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #method
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn with_to_tokens(
+    params: &WithParams,
+    vis: &syn::Visibility,
+    generics_for_impl: &syn::Generics,
+    generic_idents: &syn::Generics,
+    item_struct: &syn::ItemStruct,
+    field: &syn::Field,
+    field_index: usize,
+    tokens: &mut proc_macro2::TokenStream,
+) {
+    let ident = &item_struct.ident;
+    let where_clause = item_struct.generics.where_clause.as_ref();
+    let field_type = &field.ty;
+    let fn_name = &params.name;
+
+    let field_reference_name = field
+        .ident
+        .as_ref()
+        .map(|ident| quote! { #ident })
+        .clone()
+        .unwrap_or_else(|| {
+            let field_index = syn::Index::from(field_index);
+            quote! { #field_index }
+        });
+
+    let (value_type, value) = if params.into {
+        (
+            quote! { impl ::core::convert::Into<#field_type> },
+            quote! { ::core::convert::Into::into(value) },
+        )
+    } else {
+        (quote! { #field_type }, quote! { value })
+    };
+
+    tokens.extend(quote! {
+        impl #generics_for_impl #ident #generic_idents
+        #where_clause {
+            #[must_use]
+            #vis fn #fn_name(mut self, value: #value_type) -> Self {
+                self.#field_reference_name = #value;
+                self
+            }
+        }
+    });
+}
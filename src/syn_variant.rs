@@ -1,15 +1,111 @@
-use crate::syn_field::SynField;
+use crate::{
+    directive::{Directive, DirectiveKind, DirectiveScope},
+    directives::Directives,
+    errors::Errors,
+    field_format::FieldFormat,
+    syn_field::SynField,
+};
 
 pub struct SynVariant {
     pub variant: syn::Variant,
+    /// directives of the variant itself
+    pub directives: Directives,
     pub fields: Vec<SynField>,
+    /// the checked format string of the `display` directive of the variant
+    pub display_format: Option<FieldFormat>,
+    /// index of the field returned by `Error::source()`
+    pub error_source: Option<usize>,
 }
 
 impl SynVariant {
-    pub fn parse(variant: &mut syn::Variant) -> syn::Result<Self> {
-        Ok(SynVariant {
-            fields: SynField::parse(&mut variant.fields)?,
+    pub fn parse(variant: &mut syn::Variant, errors: &mut Errors) -> Self {
+        SynVariant {
+            directives: Directives::parse_attributes(
+                &mut variant.attrs,
+                Some(variant.ident.clone()),
+                DirectiveScope::EnumVariant,
+                errors,
+            ),
+            fields: SynField::parse(
+                &mut variant.fields,
+                DirectiveScope::EnumVariantField,
+                errors,
+            ),
             variant: variant.clone(),
-        })
+            display_format: None,
+            error_source: None,
+        }
+    }
+}
+
+impl SynVariant {
+    /// whether the variant opts out of the given container-level directive by `skip`
+    fn skips(&self, directive_name: &str) -> bool {
+        self.directives
+            .iter()
+            .any(|directive| match &directive.kind {
+                DirectiveKind::Skip(params) => params.skips(directive_name),
+                _ => false,
+            })
+    }
+
+    /// Applies the container-level directives that generate methods for the variants (e.g., `is`)
+    /// to the variant, and the ones of the fields (e.g., `from`) to the field of a single-field
+    /// variant. The variant opts out by `skip`, or overrides the directive by specifying a
+    /// directive of the same kind.
+    pub fn apply_container_directives(
+        &mut self,
+        container_directives: &Directives,
+        errors: &mut Errors,
+    ) {
+        let own_directive_count = self.directives.iter().count();
+
+        for container_directive in container_directives.iter() {
+            let name = container_directive.kind.to_string();
+
+            if !DirectiveScope::EnumVariant
+                .variant_directives()
+                .contains(&name.as_str())
+                || self.skips(&name)
+            {
+                continue;
+            }
+
+            let directive = syn::parse::Parser::parse2(
+                |input: syn::parse::ParseStream<'_>| {
+                    Directive::parse(
+                        input,
+                        Some(self.variant.ident.clone()),
+                        DirectiveScope::EnumVariant,
+                    )
+                },
+                container_directive.tokens.clone(),
+            );
+            let Some(directive) = errors.handle(directive) else {
+                continue;
+            };
+
+            let is_overridden = self
+                .directives
+                .iter()
+                .take(own_directive_count)
+                .any(|own| own.kind.to_string() == name);
+            if is_overridden {
+                continue;
+            }
+
+            self.directives.push(directive, errors);
+        }
+
+        // the container-level directives of the fields are applied only to single-field variants
+        let field_directives =
+            container_directives.filtered(|directive| !self.skips(&directive.kind.to_string()));
+        if let [field] = self.fields.as_mut_slice() {
+            field.apply_container_directives(
+                &field_directives,
+                DirectiveScope::EnumVariantField,
+                errors,
+            );
+        }
     }
 }
@@ -0,0 +1,44 @@
+/// Visibility of a generated method, given as a string literal (e.g., `vis = "pub(crate)"`).
+#[derive(Clone)]
+pub enum VisParam {
+    Explicit(syn::Visibility),
+    /// `"inherit"`: the visibility of the field is used
+    Inherit,
+}
+
+impl VisParam {
+    /// parses `= "<visibility>"`
+    pub fn parse_value(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<syn::Token![=]>()?;
+        Self::from_lit(&input.parse()?)
+    }
+
+    /// parses the `"<visibility>"` literal
+    pub fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        if lit.value() == "inherit" {
+            return Ok(VisParam::Inherit);
+        }
+
+        lit.parse().map(VisParam::Explicit).map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                format!(
+                    "expected a visibility (e.g., `pub(crate)`) or `inherit`, found `{}`",
+                    lit.value()
+                ),
+            )
+        })
+    }
+
+    pub fn resolve<'a>(&'a self, field: &'a syn::Field) -> &'a syn::Visibility {
+        self.resolve_with(&field.vis)
+    }
+
+    /// `inherited` is used for `"inherit"`, e.g., the visibility of the struct for its methods
+    pub fn resolve_with<'a>(&'a self, inherited: &'a syn::Visibility) -> &'a syn::Visibility {
+        match self {
+            VisParam::Explicit(vis) => vis,
+            VisParam::Inherit => inherited,
+        }
+    }
+}
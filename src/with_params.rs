@@ -0,0 +1,108 @@
+use crate::{suggestion, vis_param::VisParam};
+
+#[derive(Clone)]
+pub struct WithParams {
+    pub name: syn::Ident,
+    /// the method accepts `impl Into<T>`
+    pub into: bool,
+    /// `None` means the visibility given in the arguments of the macro
+    pub vis: Option<VisParam>,
+}
+
+impl WithParams {
+    const PARAMS: &'static [&'static str] = &["name", "into", "vis"];
+
+    pub fn parse(
+        input: syn::parse::ParseStream,
+        default_name: Option<syn::Ident>,
+    ) -> syn::Result<Self> {
+        fn set_name(
+            existing: &mut Option<syn::Ident>,
+            new: syn::LitStr,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "name already specified"));
+            }
+            *existing = Some(syn::Ident::new(&new.value(), new.span()));
+            Ok(())
+        }
+
+        fn set_into(existing: &mut bool, span: proc_macro2::Span) -> syn::Result<()> {
+            if *existing {
+                return Err(syn::Error::new(span, "into already specified"));
+            }
+            *existing = true;
+            Ok(())
+        }
+
+        fn set_vis(
+            existing: &mut Option<VisParam>,
+            input: syn::parse::ParseStream,
+            span: proc_macro2::Span,
+        ) -> syn::Result<()> {
+            if existing.is_some() {
+                return Err(syn::Error::new(span, "vis already specified"));
+            }
+            *existing = Some(VisParam::parse_value(input)?);
+            Ok(())
+        }
+
+        let mut name = None;
+        let mut into = false;
+        let mut vis = None;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            while !content.is_empty() {
+                let ident: syn::Ident = content.parse()?;
+
+                match ident.to_string().as_str() {
+                    "name" => {
+                        content.parse::<syn::Token![=]>()?;
+                        let name_lit: syn::LitStr = content.parse()?;
+                        set_name(&mut name, name_lit, ident.span())?;
+                    }
+                    "into" => {
+                        set_into(&mut into, ident.span())?;
+                    }
+                    "vis" => {
+                        set_vis(&mut vis, &content, ident.span())?;
+                    }
+                    other => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "expected {}, found `{}`{}",
+                                suggestion::expected(Self::PARAMS),
+                                other,
+                                suggestion::did_you_mean(other, Self::PARAMS),
+                            ),
+                        ));
+                    }
+                }
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        let name = match name {
+            Some(name) => name,
+            None => default_name.ok_or_else(|| {
+                syn::Error::new(
+                    input.span(),
+                    "name must be specified if no default name is provided",
+                )
+            })?,
+        };
+        let name = quote::format_ident!("with_{}", name, span = name.span());
+
+        Ok(WithParams { name, into, vis })
+    }
+}
@@ -21,4 +21,24 @@ fn compilation() {
             value: T,
         }
     }
+
+    {
+        #[attrimpl::attrimpl]
+        #[attrimpl(builder(typestate))]
+        struct Struct<'a, T: Into<String>, const N: usize = 7> {
+            value: &'a T,
+            #[attrimpl(default = [0; N])]
+            bytes: [u8; N],
+        }
+    }
+
+    {
+        #[attrimpl::attrimpl]
+        #[attrimpl(builder)]
+        struct Struct<'a, T: Into<String>, const N: usize = 7> {
+            value: &'a T,
+            #[attrimpl(builder(skip), default = [0; N])]
+            bytes: [u8; N],
+        }
+    }
 }
@@ -30,10 +30,428 @@ fn test_enum() {
 
     {
         // Boxed from f64
-        let value = Box::<Enum>::from(3.14f64);
+        let value = Box::<Enum>::from(2.5f64);
         match *value {
-            Enum::F64(f) => assert_eq!(f, 3.14f64),
+            Enum::F64(f) => assert_eq!(f, 2.5f64),
             _ => panic!("expected Enum::F64"),
         }
     }
 }
+
+#[test]
+fn test_enum_from_field_default() {
+    #[attrimpl::attrimpl]
+    enum Enum {
+        Named {
+            #[attrimpl(from(field_default))]
+            name: String,
+            #[attrimpl(default = 3)]
+            retries: u32,
+        },
+        Tuple(#[attrimpl(from(field_default))] u8, Vec<u8>),
+    }
+
+    match Enum::from("test".to_string()) {
+        Enum::Named { name, retries } => {
+            assert_eq!(name, "test");
+            assert_eq!(retries, 3);
+        }
+        _ => panic!("expected Enum::Named"),
+    }
+
+    match Enum::from(42u8) {
+        Enum::Tuple(byte, bytes) => {
+            assert_eq!(byte, 42);
+            assert!(bytes.is_empty());
+        }
+        _ => panic!("expected Enum::Tuple"),
+    }
+}
+
+#[test]
+fn test_enum_from_boxed() {
+    #[attrimpl::attrimpl]
+    enum Enum {
+        S(#[attrimpl(from(boxed))] String),
+        U8 {
+            #[attrimpl(convert(boxed))]
+            byte: u8,
+        },
+    }
+
+    match Enum::from(Box::new("test".to_string())) {
+        Enum::S(s) => assert_eq!(s, "test"),
+        _ => panic!("expected Enum::S"),
+    }
+
+    match *Box::<Enum>::from(Box::new(42u8)) {
+        Enum::U8 { byte } => assert_eq!(byte, 42),
+        _ => panic!("expected Enum::U8"),
+    }
+}
+
+#[test]
+fn test_enum_container_from() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(from)]
+    #[allow(dead_code)]
+    enum Enum {
+        S(String),
+        U8 { byte: u8 },
+        F64(#[attrimpl(skip)] f64),
+        Pair(u16, u32),
+        Unit,
+    }
+
+    impl From<f64> for Enum {
+        fn from(_value: f64) -> Self {
+            Enum::Unit
+        }
+    }
+
+    match Enum::from("test".to_string()) {
+        Enum::S(s) => assert_eq!(s, "test"),
+        _ => panic!("expected Enum::S"),
+    }
+
+    match *Box::<Enum>::from(42u8) {
+        Enum::U8 { byte } => assert_eq!(byte, 42),
+        _ => panic!("expected Enum::U8"),
+    }
+
+    assert!(matches!(Enum::from(2.5f64), Enum::Unit));
+}
+
+#[test]
+fn test_enum_default() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(default(variant = "Connecting"))]
+    #[derive(Debug, PartialEq)]
+    enum State {
+        Idle,
+        Connecting {
+            #[attrimpl(default = 3)]
+            retries: u32,
+            host: String,
+        },
+    }
+
+    assert_eq!(
+        State::default(),
+        State::Connecting {
+            retries: 3,
+            host: String::new(),
+        }
+    );
+    assert_ne!(State::default(), State::Idle);
+}
+
+#[test]
+fn test_enum_display() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(display)]
+    enum Message {
+        #[attrimpl(display("text: {0}"))]
+        Text(String),
+        #[attrimpl(display("move to ({x}, {y})"))]
+        Move {
+            x: i32,
+            y: i32,
+        },
+        Number(#[attrimpl(display(forward))] u32),
+        Quit,
+    }
+
+    assert_eq!(Message::Text("hi".to_string()).to_string(), "text: hi");
+    assert_eq!(Message::Move { x: 1, y: -2 }.to_string(), "move to (1, -2)");
+    assert_eq!(Message::Number(42).to_string(), "42");
+    assert_eq!(Message::Quit.to_string(), "Quit");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(display)]
+    enum Generic<T, U> {
+        #[attrimpl(display("a {0}"))]
+        A(T),
+        #[attrimpl(display("b {0:?}"))]
+        B(U),
+    }
+
+    assert_eq!(Generic::<_, ()>::A(1).to_string(), "a 1");
+    assert_eq!(Generic::<u8, _>::B(vec![1]).to_string(), "b [1]");
+}
+
+#[test]
+fn test_enum_error() {
+    use std::error::Error as _;
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(error, display)]
+    #[derive(Debug)]
+    enum Error {
+        #[attrimpl(display("io error: {0}"))]
+        Io(#[attrimpl(from)] std::io::Error),
+        #[attrimpl(display("invalid number `{input}`"))]
+        Parse {
+            input: String,
+            #[attrimpl(source)]
+            error: std::num::ParseIntError,
+        },
+        #[attrimpl(display("{0}"))]
+        Message(String),
+        #[attrimpl(display("request failed"))]
+        Request(#[attrimpl(source)] Box<dyn std::error::Error + Send + Sync>),
+        Unknown,
+    }
+
+    fn parse(input: &str) -> Result<u32, Error> {
+        input.parse().map_err(|error| Error::Parse {
+            input: input.to_string(),
+            error,
+        })
+    }
+
+    fn read() -> Result<(), Error> {
+        Err(std::io::Error::other("disk"))?;
+        Ok(())
+    }
+
+    let error = read().unwrap_err();
+    assert_eq!(error.to_string(), "io error: disk");
+    assert_eq!(error.source().unwrap().to_string(), "disk");
+
+    let error = parse("abc").unwrap_err();
+    assert_eq!(error.to_string(), "invalid number `abc`");
+    assert!(error.source().unwrap().is::<std::num::ParseIntError>());
+
+    assert!(Error::Message("test".to_string()).source().is_none());
+    assert!(Error::Unknown.source().is_none());
+
+    let error = Error::Request("connection reset".into());
+    assert_eq!(error.to_string(), "request failed");
+    assert_eq!(error.source().unwrap().to_string(), "connection reset");
+}
+
+#[test]
+fn test_enum_error_container_from() {
+    use std::error::Error as _;
+
+    // the container-level `from` does not make the fields sources
+    #[attrimpl::attrimpl]
+    #[attrimpl(error, display, from)]
+    #[derive(Debug)]
+    enum Error {
+        #[attrimpl(display("{0}"))]
+        Message(String),
+        #[attrimpl(display("{0}"))]
+        Io(#[attrimpl(source)] std::io::Error),
+    }
+
+    assert!(Error::from("test".to_string()).source().is_none());
+    assert!(
+        Error::from(std::io::Error::other("disk"))
+            .source()
+            .is_some()
+    );
+}
+
+#[test]
+fn test_enum_debug() {
+    fn hex(value: &u32, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#x}", value)
+    }
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    #[allow(dead_code)]
+    enum Event {
+        Login {
+            user: String,
+            #[attrimpl(debug(redact))]
+            password: String,
+        },
+        Code(
+            #[attrimpl(debug(with = hex))] u32,
+            #[attrimpl(debug(skip))] Vec<u8>,
+        ),
+        Logout,
+    }
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            Event::Login {
+                user: "jane".to_string(),
+                password: "secret".to_string(),
+            }
+        ),
+        r#"Login { user: "jane", password: "***" }"#,
+    );
+    assert_eq!(format!("{:?}", Event::Code(255, vec![1])), "Code(0xff, ..)");
+    assert_eq!(format!("{:?}", Event::Logout), "Logout");
+
+    struct NotDebug;
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    enum Response<T, U> {
+        Ok(T),
+        Err(#[attrimpl(debug(skip))] U),
+    }
+
+    assert_eq!(format!("{:?}", Response::<_, NotDebug>::Ok(3)), "Ok(3)");
+    assert_eq!(format!("{:?}", Response::<u8, _>::Err(NotDebug)), "Err(..)");
+}
+
+#[test]
+fn test_enum_into() {
+    #[attrimpl::attrimpl]
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Text(#[attrimpl(into)] String),
+        Number {
+            #[attrimpl(convert)]
+            value: i64,
+        },
+        Pair(#[attrimpl(into(boxed))] u8, u16),
+        Empty,
+    }
+
+    let value = Value::Text("test".to_string());
+    assert_eq!(<&String>::try_from(&value).unwrap(), "test");
+    assert_eq!(<&i64>::try_from(&value), Err(&value));
+    assert_eq!(String::try_from(value).unwrap(), "test");
+
+    // the error gives back the original value, so the extractions can be chained
+    let value = Value::from(42);
+    let value = String::try_from(value).unwrap_err();
+    assert_eq!(i64::try_from(value), Ok(42));
+
+    assert_eq!(u8::try_from(Value::Pair(1, 2)), Ok(1));
+    assert_eq!(u8::try_from(Box::new(Value::Pair(1, 2))), Ok(1));
+    assert_eq!(
+        u8::try_from(Box::new(Value::Empty)),
+        Err(Box::new(Value::Empty))
+    );
+}
+
+#[test]
+fn test_enum_container_into() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(convert)]
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Text(String),
+        Number(i64),
+        #[allow(dead_code)]
+        Flag(#[attrimpl(skip(into))] bool),
+    }
+
+    assert_eq!(i64::try_from(Value::from(3)), Ok(3));
+    assert_eq!(String::try_from(Value::from(3)), Err(Value::Number(3)));
+    assert_eq!(bool::try_from(Value::Flag(true)), Ok(true));
+}
+
+#[test]
+fn test_enum_variant_methods() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(is, as_variant)]
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        #[attrimpl(as_variant_mut, into_variant)]
+        PlainText(String),
+        #[attrimpl(is(name = "is_moving"), into_variant)]
+        Move {
+            x: i32,
+            y: i32,
+        },
+        #[attrimpl(as_variant_mut)]
+        Pair(u8, u16),
+        Quit,
+    }
+
+    let mut message = Message::PlainText("hi".to_string());
+    assert!(message.is_plain_text());
+    assert!(!message.is_quit());
+    assert_eq!(message.as_plain_text(), Some(&"hi".to_string()));
+    message.as_plain_text_mut().unwrap().push('!');
+    assert_eq!(message.as_move(), None);
+    assert_eq!(message.into_plain_text(), Ok("hi!".to_string()));
+
+    let message = Message::Move { x: 1, y: 2 };
+    assert!(message.is_moving());
+    assert_eq!(message.as_move(), Some((&1, &2)));
+    assert_eq!(message.into_plain_text(), Err(Message::Move { x: 1, y: 2 }));
+
+    let mut message = Message::Pair(1, 2);
+    *message.as_pair_mut().unwrap().1 = 3;
+    assert_eq!(message.as_pair(), Some((&1, &3)));
+
+    assert!(Message::Quit.is_quit());
+    assert_eq!(Message::Quit.as_quit(), Some(()));
+    assert_eq!(Message::Quit.into_move(), Err(Message::Quit));
+}
+
+#[test]
+fn test_enum_variant_skip() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(is, as_variant, from)]
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Text(String),
+        #[attrimpl(skip(as_variant, from))]
+        Raw(Vec<u8>),
+        #[attrimpl(skip)]
+        #[allow(dead_code)]
+        Number(u32),
+        #[attrimpl(from)]
+        Quit,
+    }
+
+    assert_eq!(Message::from("hi".to_string()).as_text().unwrap(), "hi");
+    assert!(Message::Raw(vec![1]).is_raw());
+    assert_eq!(Message::from(()), Message::Quit);
+    assert_eq!(*Box::<Message>::from(()), Message::Quit);
+    assert!(Message::Quit.is_quit());
+}
+
+#[test]
+fn test_enum_kind() {
+    use std::collections::HashMap;
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(kind)]
+    #[allow(dead_code)]
+    enum Event {
+        Text(String),
+        Move { x: i32, y: i32 },
+        Quit,
+    }
+
+    assert_eq!(Event::Text("hi".to_string()).kind(), EventKind::Text);
+    assert_eq!(
+        EventKind::from(&Event::Move { x: 1, y: 2 }),
+        EventKind::Move
+    );
+    assert_eq!(EventKind::Quit.to_string(), "Quit");
+    assert_eq!("Move".parse::<EventKind>(), Ok(EventKind::Move));
+
+    let error = "move".parse::<EventKind>().unwrap_err();
+    assert_eq!(error.value(), "move");
+    assert_eq!(error.to_string(), "unknown `EventKind` variant `move`");
+
+    let mut counts = HashMap::new();
+    for event in [Event::Quit, Event::Text("a".to_string()), Event::Quit] {
+        *counts.entry(event.kind()).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&EventKind::Quit], 2);
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(kind(name = "MessageKind", derive(PartialOrd, Ord)))]
+    #[allow(dead_code)]
+    enum Message<'a> {
+        Text(&'a str),
+        Quit,
+    }
+
+    assert!(Message::Text("hi").kind() < MessageKind::Quit);
+}
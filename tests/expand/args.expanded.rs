@@ -0,0 +1,31 @@
+struct CrateVisible {
+    name: String,
+}
+impl CrateVisible {
+    pub(crate) fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl CrateVisible {
+    pub(crate) fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+struct Private {
+    name: String,
+}
+impl Private {
+    fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl ::core::convert::From<String> for Private {
+    fn from(value: String) -> Self {
+        Self { name: value }
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Private> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Private::from(value))
+    }
+}
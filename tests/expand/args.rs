@@ -0,0 +1,11 @@
+#[attrimpl::attrimpl(vis = "pub(crate)")]
+struct CrateVisible {
+    #[attrimpl(access)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(vis = "")]
+struct Private {
+    #[attrimpl(get_ref, from)]
+    name: String,
+}
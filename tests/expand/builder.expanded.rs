@@ -0,0 +1,97 @@
+pub struct Config<T: Clone> {
+    name: String,
+    retries: u32,
+    host: String,
+    value: T,
+    cache: Vec<T>,
+}
+pub struct ConfigBuilder<T: Clone> {
+    name: ::core::option::Option<String>,
+    retries: ::core::option::Option<u32>,
+    host: ::core::option::Option<String>,
+    value: ::core::option::Option<T>,
+    __marker: ::core::marker::PhantomData<fn() -> Config<T>>,
+}
+impl<T: Clone> ::core::default::Default for ConfigBuilder<T> {
+    fn default() -> Self {
+        Self {
+            name: ::core::option::Option::None,
+            retries: ::core::option::Option::None,
+            host: ::core::option::Option::None,
+            value: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone> ConfigBuilder<T> {
+    #[must_use]
+    pub fn name(mut self, value: impl ::core::convert::Into<String>) -> Self {
+        self.name = ::core::option::Option::Some(::core::convert::Into::into(value));
+        self
+    }
+    #[must_use]
+    pub fn retries(mut self, value: u32) -> Self {
+        self.retries = ::core::option::Option::Some(value);
+        self
+    }
+    #[must_use]
+    pub fn host(mut self, value: String) -> Self {
+        self.host = ::core::option::Option::Some(value);
+        self
+    }
+    #[must_use]
+    pub fn value(mut self, value: T) -> Self {
+        self.value = ::core::option::Option::Some(value);
+        self
+    }
+    pub fn build(self) -> ::core::result::Result<Config<T>, ConfigBuilderError> {
+        ::core::result::Result::Ok(Config {
+            name: match self.name {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => {
+                    return ::core::result::Result::Err(ConfigBuilderError {
+                        missing_field: "name",
+                    });
+                }
+            },
+            retries: match self.retries {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => 3,
+            },
+            host: match self.host {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => "localhost".to_string(),
+            },
+            value: match self.value {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => {
+                    return ::core::result::Result::Err(ConfigBuilderError {
+                        missing_field: "value",
+                    });
+                }
+            },
+            cache: ::core::default::Default::default(),
+        })
+    }
+}
+impl<T: Clone> Config<T> {
+    pub fn builder() -> ConfigBuilder<T> {
+        ::core::default::Default::default()
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigBuilderError {
+    missing_field: &'static str,
+}
+impl ConfigBuilderError {
+    /// name of the field that is not set
+    pub fn missing_field(&self) -> &'static str {
+        self.missing_field
+    }
+}
+impl ::core::fmt::Display for ConfigBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(f, "the `{}` field of `Config` is not set", self.missing_field)
+    }
+}
+impl ::std::error::Error for ConfigBuilderError {}
@@ -0,0 +1,17 @@
+#[attrimpl::attrimpl]
+#[attrimpl(builder)]
+pub struct Config<T: Clone> {
+    #[attrimpl(builder(into))]
+    name: String,
+
+    #[attrimpl(builder(default = 3))]
+    retries: u32,
+
+    #[attrimpl(default = "localhost".to_string())]
+    host: String,
+
+    value: T,
+
+    #[attrimpl(builder(skip))]
+    cache: Vec<T>,
+}
@@ -0,0 +1,50 @@
+struct NamedStruct {
+    name: String,
+    value: usize,
+    secret: String,
+}
+impl NamedStruct {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl NamedStruct {
+    pub fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl NamedStruct {
+    pub fn value(&self) -> usize {
+        self.value
+    }
+}
+impl NamedStruct {
+    pub fn value_mut(&mut self) -> &mut usize {
+        &mut self.value
+    }
+}
+enum Enum {
+    S(String),
+    U8 { byte: u8 },
+    Pair(u16, u32),
+}
+impl ::core::convert::From<String> for Enum {
+    fn from(value: String) -> Self {
+        Self::S(value)
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Enum> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Enum::from(value))
+    }
+}
+impl ::core::convert::From<u8> for Enum {
+    fn from(value: u8) -> Self {
+        Self::U8 { byte: value }
+    }
+}
+impl ::core::convert::From<u8> for ::std::boxed::Box<Enum> {
+    fn from(value: u8) -> Self {
+        ::std::boxed::Box::new(Enum::from(value))
+    }
+}
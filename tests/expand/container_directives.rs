@@ -0,0 +1,21 @@
+#[attrimpl::attrimpl]
+#[attrimpl(get_ref, get_mut)]
+struct NamedStruct {
+    name: String,
+
+    #[attrimpl(get_copy)]
+    value: usize,
+
+    #[attrimpl(skip)]
+    secret: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(from)]
+enum Enum {
+    S(String),
+    U8 {
+        byte: u8,
+    },
+    Pair(u16, u32),
+}
@@ -0,0 +1,77 @@
+fn len(value: &String, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<{} bytes>", value.len())
+}
+struct Credentials {
+    user: String,
+    password: String,
+    token: String,
+    cache: Vec<u8>,
+}
+impl ::core::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        struct __DebugWith<'a, T: ?::core::marker::Sized>(
+            &'a T,
+            fn(&T, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+        );
+        impl<T: ?::core::marker::Sized> ::core::fmt::Debug for __DebugWith<'_, T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                (self.1)(self.0, f)
+            }
+        }
+        f.debug_struct("Credentials")
+            .field("user", &self.user)
+            .field("password", &"***")
+            .field("token", &__DebugWith(&self.token, len))
+            .finish_non_exhaustive()
+    }
+}
+struct Token<T>(T, String);
+impl<T> ::core::fmt::Debug for Token<T>
+where
+    T: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Token").field(&self.0).field(&"***").finish()
+    }
+}
+enum Event {
+    Login { user: String, password: String },
+    Code(u32, Vec<u8>),
+    Logout,
+}
+impl ::core::fmt::Debug for Event {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Login { user: __self_user, .. } => {
+                f.debug_struct("Login")
+                    .field("user", __self_user)
+                    .field("password", &"***")
+                    .finish()
+            }
+            Self::Code { 0: __self_0, .. } => {
+                f.debug_tuple("Code").field(__self_0).finish_non_exhaustive()
+            }
+            Self::Logout { .. } => f.write_str("Logout"),
+        }
+    }
+}
+enum Response<T, E> {
+    Ok(T),
+    Err { error: E, retries: Vec<E> },
+}
+impl<T, E> ::core::fmt::Debug for Response<T, E>
+where
+    T: ::core::fmt::Debug,
+    E: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Ok { 0: __self_0, .. } => f.debug_tuple("Ok").field(__self_0).finish(),
+            Self::Err { error: __self_error, .. } => {
+                f.debug_struct("Err")
+                    .field("error", __self_error)
+                    .finish_non_exhaustive()
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+fn len(value: &String, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "<{} bytes>", value.len())
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+struct Credentials {
+    user: String,
+    #[attrimpl(debug(redact))]
+    password: String,
+    #[attrimpl(debug(with = len))]
+    token: String,
+    #[attrimpl(debug(skip))]
+    cache: Vec<u8>,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+struct Token<T>(T, #[attrimpl(debug(redact))] String);
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+enum Event {
+    Login {
+        user: String,
+        #[attrimpl(debug(redact))]
+        password: String,
+    },
+    Code(u32, #[attrimpl(debug(skip))] Vec<u8>),
+    Logout,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+enum Response<T, E> {
+    Ok(T),
+    Err {
+        error: E,
+        #[attrimpl(debug(skip))]
+        retries: Vec<E>,
+    },
+}
@@ -0,0 +1,39 @@
+struct Config<T: Default> {
+    name: String,
+    value: T,
+}
+impl<T: Default> ::core::default::Default for Config<T> {
+    fn default() -> Self {
+        Self {
+            name: "anon".into(),
+            value: ::core::default::Default::default(),
+        }
+    }
+}
+struct TupleStruct(u32, String);
+impl ::core::default::Default for TupleStruct {
+    fn default() -> Self {
+        Self {
+            0: 3,
+            1: ::core::default::Default::default(),
+        }
+    }
+}
+struct Unit;
+impl ::core::default::Default for Unit {
+    fn default() -> Self {
+        Self {}
+    }
+}
+enum Enum {
+    Unit,
+    Pair(u16, u32),
+}
+impl ::core::default::Default for Enum {
+    fn default() -> Self {
+        Self::Pair {
+            0: 7,
+            1: ::core::default::Default::default(),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+#[attrimpl::attrimpl]
+#[attrimpl(default)]
+struct Config<T: Default> {
+    #[attrimpl(default = "anon".into())]
+    name: String,
+
+    value: T,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(default)]
+struct TupleStruct(#[attrimpl(default = 3)] u32, String);
+
+#[attrimpl::attrimpl]
+#[attrimpl(default)]
+struct Unit;
+
+#[attrimpl::attrimpl]
+#[attrimpl(default(variant = "Pair"))]
+enum Enum {
+    Unit,
+    Pair(#[attrimpl(default = 7)] u16, u32),
+}
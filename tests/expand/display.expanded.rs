@@ -0,0 +1,88 @@
+struct User {
+    name: String,
+    id: u64,
+}
+impl ::core::fmt::Display for User {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(f, "{name} ({id})", name = & self.name, id = & self.id)
+    }
+}
+struct Address(String, u16);
+impl ::core::fmt::Display for Address {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(f, "{_0}:{_1:>5}", _0 = & self.0, _1 = & self.1)
+    }
+}
+struct Meters(f64);
+impl ::core::fmt::Display for Meters {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(&self.0, f)
+    }
+}
+struct Wrap<T>(T);
+impl<T> ::core::fmt::Display for Wrap<T>
+where
+    T: ::core::fmt::Display,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(&self.0, f)
+    }
+}
+enum Message<T> {
+    Text(String),
+    Move { x: i32, y: i32 },
+    Value(T),
+    Quit,
+}
+impl<T> ::core::fmt::Display for Message<T>
+where
+    T: ::core::fmt::Display,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Text { 0: __self_0, .. } => {
+                ::core::write!(f, "text: {_0}", _0 = __self_0)
+            }
+            Self::Move { x: __self_x, y: __self_y, .. } => {
+                ::core::write!(f, "move to ({x}, {y})", x = __self_x, y = __self_y)
+            }
+            Self::Value { 0: __self_0, .. } => ::core::fmt::Display::fmt(__self_0, f),
+            Self::Quit { .. } => f.write_str("Quit"),
+        }
+    }
+}
+struct Tagged<T, U> {
+    value: T,
+    items: Vec<U>,
+}
+impl<T, U> ::core::fmt::Display for Tagged<T, U>
+where
+    T: ::core::fmt::Display,
+    Vec<U>: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(
+            f, "<{value}> {items:?}", value = & self.value, items = & self.items
+        )
+    }
+}
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+impl<L, R> ::core::fmt::Display for Either<L, R>
+where
+    L: ::core::fmt::Display,
+    R: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Left { 0: __self_0, .. } => {
+                ::core::write!(f, "left {_0}", _0 = __self_0)
+            }
+            Self::Right { 0: __self_0, .. } => {
+                ::core::write!(f, "right {_0:?}", _0 = __self_0)
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#[attrimpl::attrimpl]
+#[attrimpl(display("{name} ({id})"))]
+struct User {
+    name: String,
+    id: u64,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display("{0}:{1:>5}"))]
+struct Address(String, u16);
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+struct Meters(#[attrimpl(display(forward))] f64);
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+struct Wrap<T>(#[attrimpl(display(forward))] T);
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+enum Message<T> {
+    #[attrimpl(display("text: {0}"))]
+    Text(String),
+    #[attrimpl(display("move to ({x}, {y})"))]
+    Move { x: i32, y: i32 },
+    Value(#[attrimpl(display(forward))] T),
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display("<{value}> {items:?}"))]
+struct Tagged<T, U> {
+    value: T,
+    items: Vec<U>,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+enum Either<L, R> {
+    #[attrimpl(display("left {0}"))]
+    Left(L),
+    #[attrimpl(display("right {0:?}"))]
+    Right(R),
+}
@@ -0,0 +1,65 @@
+enum Enum {
+    S(String),
+    U8 { byte: u8 },
+    Tuple(f64, u32),
+}
+impl ::core::convert::From<String> for Enum {
+    fn from(value: String) -> Self {
+        Self::S(value)
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Enum> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Enum::from(value))
+    }
+}
+impl ::core::convert::TryFrom<Enum> for String {
+    type Error = Enum;
+    fn try_from(value: Enum) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Enum::S { 0: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<'__a> ::core::convert::TryFrom<&'__a Enum> for &'__a String {
+    type Error = &'__a Enum;
+    fn try_from(value: &'__a Enum) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Enum::S { 0: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl ::core::convert::From<u8> for Enum {
+    fn from(value: u8) -> Self {
+        Self::U8 { byte: value }
+    }
+}
+impl ::core::convert::From<u8> for ::std::boxed::Box<Enum> {
+    fn from(value: u8) -> Self {
+        ::std::boxed::Box::new(Enum::from(value))
+    }
+}
+impl ::core::convert::From<::std::boxed::Box<u8>> for Enum {
+    fn from(value: ::std::boxed::Box<u8>) -> Self {
+        <Self as ::core::convert::From<u8>>::from(*value)
+    }
+}
+impl ::core::convert::From<::std::boxed::Box<u8>> for ::std::boxed::Box<Enum> {
+    fn from(value: ::std::boxed::Box<u8>) -> Self {
+        ::std::boxed::Box::new(Enum::from(*value))
+    }
+}
+impl ::core::convert::From<f64> for Enum {
+    fn from(value: f64) -> Self {
+        Self::Tuple { 0: value, 1: 1 }
+    }
+}
+impl ::core::convert::From<f64> for ::std::boxed::Box<Enum> {
+    fn from(value: f64) -> Self {
+        ::std::boxed::Box::new(Enum::from(value))
+    }
+}
@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+enum Enum {
+    S(#[attrimpl(convert)] String),
+    U8 {
+        #[attrimpl(from(boxed))]
+        byte: u8,
+    },
+    Tuple(#[attrimpl(from(field_default))] f64, #[attrimpl(default = 1)] u32),
+}
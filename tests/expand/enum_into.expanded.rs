@@ -0,0 +1,57 @@
+enum Value<'a, T> {
+    Text(&'a str),
+    Number { value: i64 },
+    Other(T),
+}
+impl<'a, T> ::core::convert::TryFrom<Value<'a, T>> for &'a str {
+    type Error = Value<'a, T>;
+    fn try_from(value: Value<'a, T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Value::Text { 0: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<'__a, 'a, T> ::core::convert::TryFrom<&'__a Value<'a, T>> for &'__a &'a str {
+    type Error = &'__a Value<'a, T>;
+    fn try_from(value: &'__a Value<'a, T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Value::Text { 0: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<'a, T> ::core::convert::TryFrom<Value<'a, T>> for i64 {
+    type Error = Value<'a, T>;
+    fn try_from(value: Value<'a, T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Value::Number { value: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<'__a, 'a, T> ::core::convert::TryFrom<&'__a Value<'a, T>> for &'__a i64 {
+    type Error = &'__a Value<'a, T>;
+    fn try_from(value: &'__a Value<'a, T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Value::Number { value: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<'a, T> ::core::convert::TryFrom<::std::boxed::Box<Value<'a, T>>> for i64 {
+    type Error = ::std::boxed::Box<Value<'a, T>>;
+    fn try_from(
+        value: ::std::boxed::Box<Value<'a, T>>,
+    ) -> ::core::result::Result<Self, Self::Error> {
+        match *value {
+            Value::Number { value: value, .. } => ::core::result::Result::Ok(value),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(::std::boxed::Box::new(value)),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+enum Value<'a, T> {
+    Text(#[attrimpl(into)] &'a str),
+    Number {
+        #[attrimpl(into(boxed))]
+        value: i64,
+    },
+    Other(T),
+}
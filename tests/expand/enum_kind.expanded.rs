@@ -0,0 +1,70 @@
+pub enum Message<T> {
+    Text(String),
+    Move { x: i32, y: i32 },
+    Value(T),
+    Quit,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MessageKind {
+    Text,
+    Move,
+    Value,
+    Quit,
+}
+impl ::core::fmt::Display for MessageKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Text => f.write_str("Text"),
+            Self::Move => f.write_str("Move"),
+            Self::Value => f.write_str("Value"),
+            Self::Quit => f.write_str("Quit"),
+        }
+    }
+}
+impl ::core::str::FromStr for MessageKind {
+    type Err = MessageKindParseError;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+        match value {
+            "Text" => ::core::result::Result::Ok(Self::Text),
+            "Move" => ::core::result::Result::Ok(Self::Move),
+            "Value" => ::core::result::Result::Ok(Self::Value),
+            "Quit" => ::core::result::Result::Ok(Self::Quit),
+            _ => {
+                ::core::result::Result::Err(MessageKindParseError {
+                    value: ::std::string::ToString::to_string(value),
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageKindParseError {
+    value: ::std::string::String,
+}
+impl MessageKindParseError {
+    /// the string that is not the name of a variant
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+}
+impl ::core::fmt::Display for MessageKindParseError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(f, "unknown `MessageKind` variant `{}`", self.value)
+    }
+}
+impl ::std::error::Error for MessageKindParseError {}
+impl<T> Message<T> {
+    pub(crate) fn kind(&self) -> MessageKind {
+        match self {
+            Self::Text { .. } => MessageKind::Text,
+            Self::Move { .. } => MessageKind::Move,
+            Self::Value { .. } => MessageKind::Value,
+            Self::Quit { .. } => MessageKind::Quit,
+        }
+    }
+}
+impl<T> ::core::convert::From<&Message<T>> for MessageKind {
+    fn from(value: &Message<T>) -> Self {
+        value.kind()
+    }
+}
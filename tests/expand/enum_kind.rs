@@ -0,0 +1,8 @@
+#[attrimpl::attrimpl]
+#[attrimpl(kind(name = "MessageKind", derive(PartialOrd, Ord), vis = "pub(crate)"))]
+pub enum Message<T> {
+    Text(String),
+    Move { x: i32, y: i32 },
+    Value(T),
+    Quit,
+}
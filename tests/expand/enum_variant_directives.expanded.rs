@@ -0,0 +1,35 @@
+enum Message {
+    Text(String),
+    Raw(Vec<u8>),
+    Quit,
+}
+impl Message {
+    pub fn is_text(&self) -> bool {
+        ::core::matches!(self, Self::Text { .. })
+    }
+}
+impl ::core::convert::From<String> for Message {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Message> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Message::from(value))
+    }
+}
+impl Message {
+    pub fn is_raw(&self) -> bool {
+        ::core::matches!(self, Self::Raw { .. })
+    }
+}
+impl ::core::convert::From<()> for Message {
+    fn from(_: ()) -> Self {
+        Self::Quit {}
+    }
+}
+impl ::core::convert::From<()> for ::std::boxed::Box<Message> {
+    fn from(value: ()) -> Self {
+        ::std::boxed::Box::new(Message::from(value))
+    }
+}
@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+#[attrimpl(is, from)]
+enum Message {
+    Text(String),
+    #[attrimpl(skip(from))]
+    Raw(Vec<u8>),
+    #[attrimpl(skip, from)]
+    Quit,
+}
@@ -0,0 +1,78 @@
+enum Message<T> {
+    Text(String),
+    Move { x: i32, y: T },
+    HTTPRequest,
+}
+impl<T> Message<T> {
+    pub fn as_text(&self) -> ::core::option::Option<&String> {
+        match self {
+            Self::Text { 0: __self_0 } => ::core::option::Option::Some(__self_0),
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<T> Message<T> {
+    pub fn as_text_mut(&mut self) -> ::core::option::Option<&mut String> {
+        match self {
+            Self::Text { 0: __self_0 } => ::core::option::Option::Some(__self_0),
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<T> Message<T> {
+    pub fn is_text(&self) -> bool {
+        ::core::matches!(self, Self::Text { .. })
+    }
+}
+impl<T> Message<T> {
+    pub(crate) fn into_text(self) -> ::core::result::Result<String, Self> {
+        match self {
+            Self::Text { 0: __self_0 } => ::core::result::Result::Ok(__self_0),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<T> Message<T> {
+    pub fn is_moving(&self) -> bool {
+        ::core::matches!(self, Self::Move { .. })
+    }
+}
+impl<T> Message<T> {
+    pub fn as_move(&self) -> ::core::option::Option<(&i32, &T)> {
+        match self {
+            Self::Move { x: __self_x, y: __self_y } => {
+                ::core::option::Option::Some((__self_x, __self_y))
+            }
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<T> Message<T> {
+    pub(crate) fn into_move(self) -> ::core::result::Result<(i32, T), Self> {
+        match self {
+            Self::Move { x: __self_x, y: __self_y } => {
+                ::core::result::Result::Ok((__self_x, __self_y))
+            }
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<T> Message<T> {
+    pub fn is_http_request(&self) -> bool {
+        ::core::matches!(self, Self::HTTPRequest { .. })
+    }
+}
+impl<T> Message<T> {
+    pub(crate) fn into_http_request(self) -> ::core::result::Result<(), Self> {
+        match self {
+            Self::HTTPRequest {} => ::core::result::Result::Ok(()),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
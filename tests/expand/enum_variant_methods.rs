@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+#[attrimpl(is, into_variant(vis = "pub(crate)"))]
+enum Message<T> {
+    #[attrimpl(as_variant, as_variant_mut)]
+    Text(String),
+    #[attrimpl(is(name = "is_moving"), as_variant)]
+    Move { x: i32, y: T },
+    HTTPRequest,
+}
@@ -0,0 +1,108 @@
+#[derive(Debug)]
+struct ReadError {
+    path: String,
+    error: std::io::Error,
+}
+impl ::std::error::Error for ReadError {
+    fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+        trait __AsDynError {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static);
+        }
+        impl<T: ::std::error::Error + 'static> __AsDynError for T {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError for dyn ::std::error::Error + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError for dyn ::std::error::Error + ::core::marker::Send + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError
+        for dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        ::core::option::Option::Some(self.error.__as_dyn_error())
+    }
+}
+impl ::core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(f, "cannot read `{path}`", path = & self.path)
+    }
+}
+#[derive(Debug)]
+struct Timeout;
+impl ::std::error::Error for Timeout {}
+#[derive(Debug)]
+enum Error {
+    Io(std::io::Error),
+    Parse { input: String, error: std::num::ParseIntError },
+    Unknown,
+}
+impl ::core::convert::From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+impl ::core::convert::From<std::io::Error> for ::std::boxed::Box<Error> {
+    fn from(value: std::io::Error) -> Self {
+        ::std::boxed::Box::new(Error::from(value))
+    }
+}
+impl ::std::error::Error for Error {
+    fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+        trait __AsDynError {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static);
+        }
+        impl<T: ::std::error::Error + 'static> __AsDynError for T {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError for dyn ::std::error::Error + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError for dyn ::std::error::Error + ::core::marker::Send + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        impl __AsDynError
+        for dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static {
+            fn __as_dyn_error(&self) -> &(dyn ::std::error::Error + 'static) {
+                self
+            }
+        }
+        match self {
+            Self::Io { 0: source, .. } => {
+                ::core::option::Option::Some(source.__as_dyn_error())
+            }
+            Self::Parse { error: source, .. } => {
+                ::core::option::Option::Some(source.__as_dyn_error())
+            }
+            Self::Unknown { .. } => ::core::option::Option::None,
+        }
+    }
+}
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Io { 0: __self_0, .. } => {
+                ::core::write!(f, "io error: {_0}", _0 = __self_0)
+            }
+            Self::Parse { input: __self_input, .. } => {
+                ::core::write!(f, "invalid number `{input}`", input = __self_input)
+            }
+            Self::Unknown { .. } => f.write_str("Unknown"),
+        }
+    }
+}
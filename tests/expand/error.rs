@@ -0,0 +1,28 @@
+#[attrimpl::attrimpl]
+#[attrimpl(error, display("cannot read `{path}`"))]
+#[derive(Debug)]
+struct ReadError {
+    path: String,
+    #[attrimpl(source)]
+    error: std::io::Error,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(error)]
+#[derive(Debug)]
+struct Timeout;
+
+#[attrimpl::attrimpl]
+#[attrimpl(error, display)]
+#[derive(Debug)]
+enum Error {
+    #[attrimpl(display("io error: {0}"))]
+    Io(#[attrimpl(from)] std::io::Error),
+    #[attrimpl(display("invalid number `{input}`"))]
+    Parse {
+        input: String,
+        #[attrimpl(source)]
+        error: std::num::ParseIntError,
+    },
+    Unknown,
+}
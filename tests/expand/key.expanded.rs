@@ -0,0 +1,113 @@
+struct Entity {
+    id: u64,
+    name: String,
+}
+impl ::core::cmp::PartialEq for Entity {
+    fn eq(&self, other: &Self) -> bool {
+        ::core::cmp::PartialEq::eq(&self.id, &other.id)
+    }
+}
+impl ::core::cmp::Eq for Entity {}
+impl ::core::hash::Hash for Entity {
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.id, state);
+    }
+}
+impl ::core::cmp::PartialOrd for Entity {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+    }
+}
+impl ::core::cmp::Ord for Entity {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        ::core::cmp::Ord::cmp(&self.id, &other.id)
+    }
+}
+impl ::core::borrow::Borrow<u64> for Entity {
+    fn borrow(&self) -> &u64 {
+        &self.id
+    }
+}
+struct Version<T> {
+    major: u32,
+    minor: u32,
+    cache: T,
+}
+impl<T> ::core::cmp::PartialEq for Version<T> {
+    fn eq(&self, other: &Self) -> bool {
+        ::core::cmp::PartialEq::eq(&self.major, &other.major)
+            && ::core::cmp::PartialEq::eq(&self.minor, &other.minor)
+    }
+}
+impl<T> ::core::cmp::Eq for Version<T> {}
+impl<T> ::core::hash::Hash for Version<T> {
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.major, state);
+        ::core::hash::Hash::hash(&self.minor, state);
+    }
+}
+impl<T> ::core::cmp::PartialOrd for Version<T> {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+    }
+}
+impl<T> ::core::cmp::Ord for Version<T> {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        ::core::cmp::Ord::cmp(&self.major, &other.major)
+            .then_with(|| ::core::cmp::Ord::cmp(&self.minor, &other.minor))
+    }
+}
+struct Record<K> {
+    id: K,
+    payload: Vec<u8>,
+}
+impl<K> ::core::cmp::PartialEq for Record<K>
+where
+    K: ::core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        ::core::cmp::PartialEq::eq(&self.id, &other.id)
+    }
+}
+impl<K> ::core::cmp::Eq for Record<K>
+where
+    K: ::core::cmp::Eq,
+{}
+impl<K> ::core::hash::Hash for Record<K>
+where
+    K: ::core::hash::Hash,
+{
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.id, state);
+    }
+}
+impl<K> ::core::cmp::PartialOrd for Record<K>
+where
+    K: ::core::cmp::Ord,
+{
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+    }
+}
+impl<K> ::core::cmp::Ord for Record<K>
+where
+    K: ::core::cmp::Ord,
+{
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        ::core::cmp::Ord::cmp(&self.id, &other.id)
+    }
+}
+impl<K> ::core::borrow::Borrow<K> for Record<K> {
+    fn borrow(&self) -> &K {
+        &self.id
+    }
+}
@@ -0,0 +1,22 @@
+#[attrimpl::attrimpl]
+struct Entity {
+    #[attrimpl(key(borrow))]
+    id: u64,
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+struct Version<T> {
+    #[attrimpl(key)]
+    major: u32,
+    #[attrimpl(key)]
+    minor: u32,
+    cache: T,
+}
+
+#[attrimpl::attrimpl]
+struct Record<K> {
+    #[attrimpl(key(borrow))]
+    id: K,
+    payload: Vec<u8>,
+}
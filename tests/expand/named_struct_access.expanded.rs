@@ -0,0 +1,40 @@
+struct NamedStruct<T: Clone> {
+    name: String,
+    value: usize,
+    generic: T,
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn name_cloned(&self) -> String {
+        ::std::clone::Clone::clone(&self.name)
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn val(&self) -> usize {
+        self.value
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn val_mut(&mut self) -> &mut usize {
+        &mut self.value
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn generic(&self) -> T {
+        ::std::clone::Clone::clone(&self.generic)
+    }
+}
+impl<T: Clone> NamedStruct<T> {
+    pub fn generic_mut(&mut self) -> &mut T {
+        &mut self.generic
+    }
+}
@@ -0,0 +1,11 @@
+#[attrimpl::attrimpl]
+struct NamedStruct<T: Clone> {
+    #[attrimpl(get_ref, get_mut, get_clone(name = "name_cloned"))]
+    name: String,
+
+    #[attrimpl(access(name = "val", get_copy))]
+    value: usize,
+
+    #[attrimpl(access(get_clone))]
+    generic: T,
+}
@@ -0,0 +1,92 @@
+struct Single {
+    name: String,
+}
+impl ::core::convert::From<String> for Single {
+    fn from(value: String) -> Self {
+        Self { name: value }
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Single> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Single::from(value))
+    }
+}
+impl ::core::convert::Into<String> for Single {
+    fn into(self) -> String {
+        self.name
+    }
+}
+struct Boxed {
+    name: String,
+}
+impl ::core::convert::From<String> for Boxed {
+    fn from(value: String) -> Self {
+        Self { name: value }
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Boxed> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Boxed::from(value))
+    }
+}
+impl ::core::convert::From<::std::boxed::Box<String>> for Boxed {
+    fn from(value: ::std::boxed::Box<String>) -> Self {
+        <Self as ::core::convert::From<String>>::from(*value)
+    }
+}
+impl ::core::convert::From<::std::boxed::Box<String>> for ::std::boxed::Box<Boxed> {
+    fn from(value: ::std::boxed::Box<String>) -> Self {
+        ::std::boxed::Box::new(Boxed::from(*value))
+    }
+}
+impl ::core::convert::Into<String> for Boxed {
+    fn into(self) -> String {
+        self.name
+    }
+}
+impl ::core::convert::Into<::std::boxed::Box<String>> for Boxed {
+    fn into(self) -> ::std::boxed::Box<String> {
+        ::std::boxed::Box::new(self.name)
+    }
+}
+impl ::core::convert::Into<String> for ::std::boxed::Box<Boxed> {
+    fn into(self) -> String {
+        (*self).name
+    }
+}
+impl ::core::convert::Into<::std::boxed::Box<String>> for ::std::boxed::Box<Boxed> {
+    fn into(self) -> ::std::boxed::Box<String> {
+        ::std::boxed::Box::new((*self).name)
+    }
+}
+struct Defaults {
+    name: String,
+    retries: u32,
+}
+impl ::core::convert::From<String> for Defaults {
+    fn from(value: String) -> Self {
+        Self { name: value, retries: 3 }
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<Defaults> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(Defaults::from(value))
+    }
+}
+struct ContainerDefault {
+    name: String,
+    retries: u32,
+}
+impl ::core::convert::From<String> for ContainerDefault {
+    fn from(value: String) -> Self {
+        Self {
+            name: value,
+            ..::core::default::Default::default()
+        }
+    }
+}
+impl ::core::convert::From<String> for ::std::boxed::Box<ContainerDefault> {
+    fn from(value: String) -> Self {
+        ::std::boxed::Box::new(ContainerDefault::from(value))
+    }
+}
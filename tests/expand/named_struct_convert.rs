@@ -0,0 +1,28 @@
+#[attrimpl::attrimpl]
+struct Single {
+    #[attrimpl(from, into)]
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+struct Boxed {
+    #[attrimpl(convert(boxed))]
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+struct Defaults {
+    #[attrimpl(from(field_default))]
+    name: String,
+
+    #[attrimpl(default = 3)]
+    retries: u32,
+}
+
+#[attrimpl::attrimpl]
+struct ContainerDefault {
+    #[attrimpl(from(container_default))]
+    name: String,
+
+    retries: u32,
+}
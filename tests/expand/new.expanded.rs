@@ -0,0 +1,23 @@
+pub struct NamedStruct<T: Clone> {
+    name: String,
+    value: T,
+    retries: u32,
+}
+impl<T: Clone> NamedStruct<T> {
+    pub(crate) fn new(name: String, value: T) -> Self {
+        Self { name, value, retries: 3 }
+    }
+}
+struct TupleStruct(String, Vec<u8>, u64);
+impl TupleStruct {
+    pub fn new(
+        value_0: impl ::core::convert::Into<String>,
+        value_2: impl ::core::convert::Into<u64>,
+    ) -> Self {
+        Self {
+            0: ::core::convert::Into::into(value_0),
+            1: ::core::default::Default::default(),
+            2: ::core::convert::Into::into(value_2),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#[attrimpl::attrimpl]
+#[attrimpl(new(vis = "pub(crate)"))]
+pub struct NamedStruct<T: Clone> {
+    name: String,
+    value: T,
+
+    #[attrimpl(default = 3)]
+    retries: u32,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(new(into))]
+struct TupleStruct(String, #[attrimpl(default)] Vec<u8>, u64);
@@ -0,0 +1,37 @@
+struct NamedStruct {
+    name: String,
+    value: usize,
+    id: u64,
+}
+impl NamedStruct {
+    pub fn set_name(&mut self, value: impl ::core::convert::Into<String>) -> &mut Self {
+        self.name = ::core::convert::Into::into(value);
+        self
+    }
+}
+impl NamedStruct {
+    pub(crate) fn set_value(&mut self, value: usize) -> usize {
+        ::core::mem::replace(&mut self.value, value)
+    }
+}
+impl NamedStruct {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+impl NamedStruct {
+    pub(crate) fn id_mut(&mut self) -> &mut u64 {
+        &mut self.id
+    }
+}
+impl NamedStruct {
+    pub fn set_id(&mut self, value: u64) {
+        self.id = value;
+    }
+}
+struct TupleStruct(String);
+impl TupleStruct {
+    pub fn set_inner(&mut self, value: String) {
+        self.0 = value;
+    }
+}
@@ -0,0 +1,15 @@
+#[attrimpl::attrimpl]
+#[attrimpl(set)]
+struct NamedStruct {
+    #[attrimpl(set(into, chain))]
+    name: String,
+
+    #[attrimpl(set(previous, vis = "pub(crate)"))]
+    value: usize,
+
+    #[attrimpl(skip, access(get_copy, set, mut_vis = "pub(crate)"))]
+    id: u64,
+}
+
+#[attrimpl::attrimpl]
+struct TupleStruct(#[attrimpl(set(name = "inner"))] String);
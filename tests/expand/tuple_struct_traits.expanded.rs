@@ -0,0 +1,65 @@
+struct TupleStruct<'a, const N: usize = 7>(&'a mut [u8; N]);
+impl<'a, const N: usize> ::core::ops::Deref for TupleStruct<'a, N> {
+    type Target = &'a mut [u8; N];
+    fn deref(&self) -> &&'a mut [u8; N] {
+        &self.0
+    }
+}
+impl<'a, const N: usize> ::core::ops::DerefMut for TupleStruct<'a, N> {
+    fn deref_mut(&mut self) -> &mut &'a mut [u8; N] {
+        &mut self.0
+    }
+}
+impl<'a, const N: usize> ::core::convert::AsRef<&'a mut [u8; N]> for TupleStruct<'a, N> {
+    fn as_ref(&self) -> &&'a mut [u8; N] {
+        &self.0
+    }
+}
+impl<'a, const N: usize> ::core::convert::AsMut<&'a mut [u8; N]> for TupleStruct<'a, N> {
+    fn as_mut(&mut self) -> &mut &'a mut [u8; N] {
+        &mut self.0
+    }
+}
+impl<'a, const N: usize> ::core::convert::Into<&'a mut [u8; N]> for TupleStruct<'a, N> {
+    fn into(self) -> &'a mut [u8; N] {
+        self.0
+    }
+}
+struct Wrapper<T>(
+    T,
+)
+where
+    T: Clone;
+impl<T> ::core::convert::From<T> for Wrapper<T>
+where
+    T: Clone,
+{
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+impl<T> ::core::convert::From<T> for ::std::boxed::Box<Wrapper<T>>
+where
+    T: Clone,
+{
+    fn from(value: T) -> Self {
+        ::std::boxed::Box::new(Wrapper::from(value))
+    }
+}
+impl<T> ::core::ops::Deref for Wrapper<T>
+where
+    T: Clone,
+{
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+impl<T> ::core::convert::AsRef<T> for Wrapper<T>
+where
+    T: Clone,
+{
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
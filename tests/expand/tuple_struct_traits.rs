@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+struct TupleStruct<'a, const N: usize = 7>(
+    #[attrimpl(deref_mut, as, into)] &'a mut [u8; N],
+);
+
+#[attrimpl::attrimpl]
+struct Wrapper<T>(#[attrimpl(from, deref, as_ref)] T)
+where
+    T: Clone;
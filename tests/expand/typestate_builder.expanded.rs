@@ -0,0 +1,99 @@
+pub struct Config<T: Clone>
+where
+    T: Default,
+{
+    name: String,
+    value: T,
+    retries: u32,
+    cache: Vec<T>,
+}
+pub struct ConfigBuilderUnset;
+pub struct ConfigBuilderSet<T>(T);
+pub struct ConfigBuilder<T: Clone, __S0, __S1>
+where
+    T: Default,
+{
+    name: __S0,
+    value: __S1,
+    retries: ::core::option::Option<u32>,
+    __marker: ::core::marker::PhantomData<fn() -> Config<T>>,
+}
+impl<T: Clone> ::core::default::Default
+for ConfigBuilder<T, ConfigBuilderUnset, ConfigBuilderUnset>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            name: ConfigBuilderUnset,
+            value: ConfigBuilderUnset,
+            retries: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone, __S1> ConfigBuilder<T, ConfigBuilderUnset, __S1>
+where
+    T: Default,
+{
+    #[must_use]
+    pub fn name(
+        self,
+        value: impl ::core::convert::Into<String>,
+    ) -> ConfigBuilder<T, ConfigBuilderSet<String>, __S1> {
+        ConfigBuilder {
+            name: ConfigBuilderSet(::core::convert::Into::into(value)),
+            value: self.value,
+            retries: self.retries,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone, __S0> ConfigBuilder<T, __S0, ConfigBuilderUnset>
+where
+    T: Default,
+{
+    #[must_use]
+    pub fn value(self, value: T) -> ConfigBuilder<T, __S0, ConfigBuilderSet<T>> {
+        ConfigBuilder {
+            value: ConfigBuilderSet(value),
+            name: self.name,
+            retries: self.retries,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone, __S0, __S1> ConfigBuilder<T, __S0, __S1>
+where
+    T: Default,
+{
+    #[must_use]
+    pub fn retries(mut self, value: u32) -> Self {
+        self.retries = ::core::option::Option::Some(value);
+        self
+    }
+}
+impl<T: Clone> ConfigBuilder<T, ConfigBuilderSet<String>, ConfigBuilderSet<T>>
+where
+    T: Default,
+{
+    pub fn build(self) -> Config<T> {
+        Config {
+            name: self.name.0,
+            value: self.value.0,
+            retries: match self.retries {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => 3,
+            },
+            cache: ::core::default::Default::default(),
+        }
+    }
+}
+impl<T: Clone> Config<T>
+where
+    T: Default,
+{
+    pub fn builder() -> ConfigBuilder<T, ConfigBuilderUnset, ConfigBuilderUnset> {
+        ::core::default::Default::default()
+    }
+}
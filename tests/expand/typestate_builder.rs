@@ -0,0 +1,17 @@
+#[attrimpl::attrimpl]
+#[attrimpl(builder(typestate))]
+pub struct Config<T: Clone>
+where
+    T: Default,
+{
+    #[attrimpl(builder(into))]
+    name: String,
+
+    value: T,
+
+    #[attrimpl(default = 3)]
+    retries: u32,
+
+    #[attrimpl(builder(skip))]
+    cache: Vec<T>,
+}
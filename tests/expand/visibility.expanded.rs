@@ -0,0 +1,49 @@
+pub struct NamedStruct {
+    pub name: String,
+    value: usize,
+    pub(crate) id: u64,
+}
+impl NamedStruct {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl NamedStruct {
+    pub(crate) fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl NamedStruct {
+    pub fn value(&self) -> &usize {
+        &self.value
+    }
+}
+impl NamedStruct {
+    pub(super) fn value_mut(&mut self) -> &mut usize {
+        &mut self.value
+    }
+}
+impl NamedStruct {
+    pub(crate) fn id(&self) -> &u64 {
+        &self.id
+    }
+}
+impl NamedStruct {
+    pub(crate) fn id_mut(&mut self) -> &mut u64 {
+        &mut self.id
+    }
+}
+pub struct Inherited {
+    pub name: String,
+    id: u64,
+}
+impl Inherited {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+impl Inherited {
+    fn id(&self) -> &u64 {
+        &self.id
+    }
+}
@@ -0,0 +1,18 @@
+#[attrimpl::attrimpl(vis = "pub(crate)")]
+pub struct NamedStruct {
+    #[attrimpl(get_ref(vis = "pub"), get_mut)]
+    pub name: String,
+
+    #[attrimpl(access(get_vis = "pub", mut_vis = "pub(super)"))]
+    value: usize,
+
+    #[attrimpl(access(vis = "inherit"))]
+    pub(crate) id: u64,
+}
+
+#[attrimpl::attrimpl(vis = "inherit")]
+#[attrimpl(get_ref)]
+pub struct Inherited {
+    pub name: String,
+    id: u64,
+}
@@ -0,0 +1,19 @@
+struct Config<T> {
+    name: String,
+    value: T,
+    id: u64,
+}
+impl<T> Config<T> {
+    #[must_use]
+    pub fn with_name(mut self, value: impl ::core::convert::Into<String>) -> Self {
+        self.name = ::core::convert::Into::into(value);
+        self
+    }
+}
+impl<T> Config<T> {
+    #[must_use]
+    pub(crate) fn with_inner(mut self, value: T) -> Self {
+        self.value = value;
+        self
+    }
+}
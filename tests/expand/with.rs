@@ -0,0 +1,12 @@
+#[attrimpl::attrimpl]
+#[attrimpl(with)]
+struct Config<T> {
+    #[attrimpl(with(into))]
+    name: String,
+
+    #[attrimpl(with(name = "inner", vis = "pub(crate)"))]
+    value: T,
+
+    #[attrimpl(skip)]
+    id: u64,
+}
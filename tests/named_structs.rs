@@ -153,3 +153,658 @@ fn named_struct_access() {
     let _r: usize = value.l();
     let _r: &mut usize = value.l_mut();
 }
+
+#[test]
+fn named_struct_from_field_default() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(from(field_default))]
+        name: String,
+
+        #[attrimpl(default = 3)]
+        retries: u32,
+
+        tags: Vec<String>,
+    }
+
+    let value = NamedStruct::from("test".to_string());
+    assert_eq!(value.name, "test");
+    assert_eq!(value.retries, 3);
+    assert!(value.tags.is_empty());
+
+    // Boxed from String
+    let value = Box::<NamedStruct>::from("test".to_string());
+    assert_eq!(value.retries, 3);
+}
+
+#[test]
+fn named_struct_from_container_default() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(convert(container_default))]
+        name: String,
+
+        retries: u32,
+    }
+
+    impl Default for NamedStruct {
+        fn default() -> Self {
+            Self {
+                name: "anon".to_string(),
+                retries: 5,
+            }
+        }
+    }
+
+    let value = NamedStruct::from("test".to_string());
+    assert_eq!(value.name, "test");
+    assert_eq!(value.retries, 5);
+
+    let value: String = value.into();
+    assert_eq!(value, "test");
+}
+
+#[test]
+fn named_struct_boxed_convert() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(from(boxed), into(boxed))]
+        name: String,
+    }
+
+    // from Box<String>
+    let value = NamedStruct::from(Box::new("test".to_string()));
+    assert_eq!(value.name, "test");
+
+    // Boxed from Box<String>
+    let value = Box::<NamedStruct>::from(Box::new("test".to_string()));
+    assert_eq!(value.name, "test");
+
+    // Boxed into String
+    let name: String = value.into();
+    assert_eq!(name, "test");
+
+    // into Box<String>
+    let name: Box<String> = NamedStruct::from("test".to_string()).into();
+    assert_eq!(*name, "test");
+
+    // Boxed into Box<String>
+    let name: Box<String> = Box::<NamedStruct>::from("test".to_string()).into();
+    assert_eq!(*name, "test");
+}
+
+#[test]
+fn named_struct_container_directives() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(get_ref, get_mut)]
+    struct NamedStruct {
+        name: String,
+
+        #[attrimpl(get_copy)]
+        value: usize,
+
+        #[attrimpl(skip(get_mut))]
+        id: u64,
+
+        #[attrimpl(skip)]
+        secret: String,
+    }
+
+    impl NamedStruct {
+        // would collide with the getter if `skip` was ignored
+        fn secret(&self) -> usize {
+            self.secret.len()
+        }
+    }
+
+    let mut value = NamedStruct {
+        name: "test".to_string(),
+        value: 42,
+        id: 7,
+        secret: "hidden".to_string(),
+    };
+
+    // container-level get_ref and get_mut
+    value.name_mut().push_str("ing");
+    let _r: &String = value.name();
+    assert_eq!(value.name(), "testing");
+
+    // get_copy overrides the container-level get_ref
+    let _r: usize = value.value();
+    *value.value_mut() += 1;
+    assert_eq!(value.value(), 43);
+
+    // get_mut is skipped
+    let _r: &u64 = value.id();
+
+    // every container-level directive is skipped
+    assert_eq!(value.secret(), 6);
+}
+
+#[test]
+fn named_struct_args() {
+    #[attrimpl::attrimpl(vis = "pub(crate)", crate = "::attrimpl")]
+    struct NamedStruct {
+        #[attrimpl(access)]
+        name: String,
+    }
+
+    let mut value = NamedStruct {
+        name: "test".to_string(),
+    };
+    value.name_mut().push_str("ing");
+    assert_eq!(value.name(), "testing");
+}
+
+mod visibility {
+    #[attrimpl::attrimpl]
+    pub struct NamedStruct {
+        #[attrimpl(access(get_vis = "pub", mut_vis = "pub(super)"))]
+        pub(super) name: String,
+
+        #[attrimpl(get_copy(vis = "inherit"))]
+        pub(super) value: usize,
+    }
+}
+
+#[test]
+fn named_struct_visibility() {
+    let mut value = visibility::NamedStruct {
+        name: "test".to_string(),
+        value: 42,
+    };
+
+    value.name_mut().push_str("ing");
+    assert_eq!(value.name(), "testing");
+    assert_eq!(value.value(), 42);
+}
+
+#[test]
+fn named_struct_set() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(get_ref, set(into))]
+        name: String,
+
+        #[attrimpl(get_copy, set(chain))]
+        value: usize,
+
+        #[attrimpl(get_copy, set(previous, name = "identifier"))]
+        id: u64,
+
+        #[attrimpl(access(set))]
+        hobby: String,
+    }
+
+    let mut value = NamedStruct {
+        name: "test".to_string(),
+        value: 42,
+        id: 7,
+        hobby: "chess".to_string(),
+    };
+
+    // set(into)
+    value.set_name("testing");
+    assert_eq!(value.name(), "testing");
+
+    // set(chain)
+    value.set_value(43).set_value(44);
+    assert_eq!(value.value(), 44);
+
+    // set(previous)
+    let previous: u64 = value.set_identifier(8);
+    assert_eq!(previous, 7);
+    assert_eq!(value.id(), 8);
+
+    // access(set)
+    value.set_hobby("go".to_string());
+    value.hobby_mut().push_str("lf");
+    assert_eq!(value.hobby(), "golf");
+}
+
+#[test]
+fn named_struct_set_raw_identifier() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(get_ref, get_mut, set)]
+        r#type: String,
+
+        #[attrimpl(access(set))]
+        r#match: u32,
+    }
+
+    let mut value = NamedStruct {
+        r#type: "test".to_string(),
+        r#match: 1,
+    };
+
+    value.set_type("testing".to_string());
+    value.type_mut().push('!');
+    assert_eq!(value.r#type(), "testing!");
+
+    value.set_match(2);
+    *value.match_mut() += 1;
+    assert_eq!(*value.r#match(), 3);
+}
+
+#[test]
+fn named_struct_with() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(with)]
+    #[derive(Default)]
+    struct Config {
+        #[attrimpl(with(into), get_ref)]
+        name: String,
+
+        #[attrimpl(get_copy)]
+        timeout: std::time::Duration,
+
+        #[attrimpl(with(name = "attempts"), get_copy)]
+        retries: u32,
+    }
+
+    let config = Config::default()
+        .with_name("test")
+        .with_timeout(std::time::Duration::from_secs(5))
+        .with_attempts(3);
+
+    assert_eq!(config.name(), "test");
+    assert_eq!(config.timeout(), std::time::Duration::from_secs(5));
+    assert_eq!(config.retries(), 3);
+}
+
+#[test]
+fn named_struct_with_raw_identifier() {
+    #[attrimpl::attrimpl]
+    struct NamedStruct {
+        #[attrimpl(get_ref, with)]
+        r#type: String,
+    }
+
+    let value = NamedStruct {
+        r#type: "test".to_string(),
+    }
+    .with_type("testing".to_string());
+    assert_eq!(value.r#type(), "testing");
+}
+
+#[test]
+fn named_struct_builder() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(builder)]
+    #[derive(Debug)]
+    struct Config {
+        #[attrimpl(builder(into))]
+        name: String,
+
+        #[attrimpl(builder(default = 3))]
+        retries: u32,
+
+        #[attrimpl(default = "localhost".to_string())]
+        host: String,
+
+        #[attrimpl(builder(skip))]
+        cache: Vec<String>,
+    }
+
+    let config = Config::builder().name("test").build().unwrap();
+    assert_eq!(config.name, "test");
+    assert_eq!(config.retries, 3);
+    assert_eq!(config.host, "localhost");
+    assert!(config.cache.is_empty());
+
+    let config = ConfigBuilder::default()
+        .name("test")
+        .retries(5)
+        .host("example.com".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(config.retries, 5);
+    assert_eq!(config.host, "example.com");
+
+    let error = Config::builder().retries(5).build().unwrap_err();
+    assert_eq!(error.missing_field(), "name");
+    assert_eq!(error.to_string(), "the `name` field of `Config` is not set");
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn named_struct_typestate_builder() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(builder(typestate))]
+    struct Config<'a, T: Clone>
+    where
+        T: Default,
+    {
+        #[attrimpl(builder(into))]
+        name: String,
+
+        label: &'a str,
+
+        #[attrimpl(builder(default = 3))]
+        retries: u32,
+
+        #[attrimpl(builder(skip))]
+        cache: Vec<T>,
+    }
+
+    // the required fields can be set in any order, optional ones at any time
+    let config = Config::<u8>::builder()
+        .retries(5)
+        .label("label")
+        .name("test")
+        .build();
+    assert_eq!(config.name, "test");
+    assert_eq!(config.label, "label");
+    assert_eq!(config.retries, 5);
+    assert!(config.cache.is_empty());
+
+    let config = ConfigBuilder::<u8, _, _>::default()
+        .name("test")
+        .label("label")
+        .build();
+    assert_eq!(config.retries, 3);
+
+    // the states are not named by the fields, `foo_bar` and `foo__bar` would collide
+    #[attrimpl::attrimpl]
+    #[attrimpl(builder(typestate))]
+    struct Similar {
+        foo_bar: u8,
+        foo__bar: u8,
+    }
+
+    let similar = Similar::builder().foo__bar(2).foo_bar(1).build();
+    assert_eq!((similar.foo_bar, similar.foo__bar), (1, 2));
+}
+
+#[test]
+fn named_struct_new() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(new)]
+    struct NamedStruct<T> {
+        name: String,
+        value: T,
+
+        #[attrimpl(default = 3)]
+        retries: u32,
+
+        #[attrimpl(default)]
+        cache: Vec<String>,
+    }
+
+    let value = NamedStruct::new("test".to_string(), 42u8);
+    assert_eq!(value.name, "test");
+    assert_eq!(value.value, 42);
+    assert_eq!(value.retries, 3);
+    assert!(value.cache.is_empty());
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(new(into))]
+    struct Into {
+        name: String,
+        id: u64,
+    }
+
+    let value = Into::new("test", 7u32);
+    assert_eq!(value.name, "test");
+    assert_eq!(value.id, 7);
+}
+
+#[test]
+fn named_struct_default() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(default)]
+    struct Config {
+        #[attrimpl(default = "anon".into())]
+        name: String,
+
+        #[attrimpl(default = 3)]
+        retries: u32,
+
+        cache: Vec<String>,
+    }
+
+    let config = Config::default();
+    assert_eq!(config.name, "anon");
+    assert_eq!(config.retries, 3);
+    assert!(config.cache.is_empty());
+
+    // the default values are shared with `from(container_default)`
+    #[attrimpl::attrimpl]
+    #[attrimpl(default)]
+    struct Entity {
+        #[attrimpl(from(container_default))]
+        id: u64,
+
+        #[attrimpl(default = "anon".into())]
+        name: String,
+    }
+
+    let entity = Entity::from(7);
+    assert_eq!(entity.id, 7);
+    assert_eq!(entity.name, "anon");
+}
+
+#[test]
+fn named_struct_display() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(display("{name} ({id:>4}) {{escaped}}"))]
+    struct User {
+        name: String,
+        id: u64,
+    }
+
+    let user = User {
+        name: "Jane".to_string(),
+        id: 7,
+    };
+    assert_eq!(user.to_string(), "Jane (   7) {escaped}");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(display)]
+    struct Name {
+        #[attrimpl(display(forward))]
+        name: String,
+        id: u64,
+    }
+
+    let name = Name {
+        name: "Jane".to_string(),
+        id: 7,
+    };
+    assert_eq!(format!("{:>6}", name), "  Jane");
+    assert_eq!(name.id, 7);
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(display("<{value}> {items:?} {mask:#x}"))]
+    struct Generic<T, U, M> {
+        value: T,
+        items: U,
+        mask: M,
+    }
+
+    let generic = Generic {
+        value: "text",
+        items: vec![1, 2],
+        mask: 255u8,
+    };
+    assert_eq!(generic.to_string(), "<text> [1, 2] 0xff");
+}
+
+#[test]
+fn named_struct_error() {
+    use std::error::Error as _;
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(error, display("cannot read `{path}`"))]
+    #[derive(Debug)]
+    struct ReadError {
+        path: String,
+        #[attrimpl(source)]
+        error: std::io::Error,
+    }
+
+    let error = ReadError {
+        path: "config.toml".to_string(),
+        error: std::io::Error::other("disk"),
+    };
+    assert_eq!(error.to_string(), "cannot read `config.toml`");
+    assert_eq!(error.source().unwrap().to_string(), "disk");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(error, display("timeout"))]
+    #[derive(Debug)]
+    struct Timeout {
+        seconds: u64,
+    }
+
+    let error = Timeout { seconds: 3 };
+    assert!(error.source().is_none());
+    assert_eq!(error.seconds, 3);
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(error, display("request failed"))]
+    #[derive(Debug)]
+    struct RequestError {
+        #[attrimpl(source)]
+        error: Box<dyn std::error::Error + Send + Sync>,
+    }
+
+    let error = RequestError {
+        error: "connection reset".into(),
+    };
+    assert_eq!(error.source().unwrap().to_string(), "connection reset");
+}
+
+#[test]
+fn named_struct_debug() {
+    fn last_digits(value: &u64, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "...{:02}", value % 100)
+    }
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    struct Credentials {
+        user: String,
+        #[attrimpl(debug(redact))]
+        password: String,
+        #[attrimpl(debug(with = last_digits))]
+        token: u64,
+    }
+
+    let credentials = Credentials {
+        user: "jane".to_string(),
+        password: "secret".to_string(),
+        token: 123456,
+    };
+    assert_eq!(
+        format!("{:?}", credentials),
+        r#"Credentials { user: "jane", password: "***", token: ...56 }"#,
+    );
+    assert_eq!(credentials.password, "secret");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    struct Session {
+        id: u64,
+        #[attrimpl(debug(skip))]
+        cache: Vec<u8>,
+    }
+
+    let session = Session {
+        id: 3,
+        cache: vec![1],
+    };
+    assert_eq!(format!("{:?}", session), "Session { id: 3, .. }");
+    assert_eq!(session.cache, [1]);
+
+    struct NotDebug;
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    struct Generic<T, U> {
+        value: T,
+        #[attrimpl(debug(redact))]
+        secret: U,
+    }
+
+    let generic = Generic {
+        value: 42,
+        secret: NotDebug,
+    };
+    assert_eq!(
+        format!("{:?}", generic),
+        r#"Generic { value: 42, secret: "***" }"#
+    );
+    assert!(matches!(generic.secret, NotDebug));
+}
+
+#[test]
+fn named_struct_key() {
+    use std::collections::{BTreeSet, HashSet};
+
+    #[attrimpl::attrimpl]
+    #[derive(Debug)]
+    struct Entity {
+        #[attrimpl(key(borrow))]
+        id: u64,
+        name: String,
+    }
+
+    let entity = |id, name: &str| Entity {
+        id,
+        name: name.to_string(),
+    };
+
+    assert_eq!(entity(1, "a"), entity(1, "b"));
+    assert_ne!(entity(1, "a"), entity(2, "a"));
+    assert!(entity(1, "b") < entity(2, "a"));
+
+    let entities = HashSet::from([entity(1, "a"), entity(2, "b")]);
+    assert_eq!(entities.get(&2).unwrap().name, "b");
+    assert!(!entities.contains(&3));
+
+    #[attrimpl::attrimpl]
+    #[derive(Debug)]
+    struct Version {
+        #[attrimpl(key)]
+        major: u32,
+        cached_name: String,
+        #[attrimpl(key)]
+        minor: u32,
+    }
+
+    let version = |major, minor| Version {
+        major,
+        cached_name: format!("{}.{}", major, minor),
+        minor,
+    };
+
+    let versions = BTreeSet::from([version(1, 10), version(2, 0), version(1, 2)]);
+    assert_eq!(
+        versions
+            .iter()
+            .map(|version| version.cached_name.as_str())
+            .collect::<Vec<_>>(),
+        ["1.2", "1.10", "2.0"],
+    );
+
+    #[attrimpl::attrimpl]
+    struct Record<K, V> {
+        #[attrimpl(key(borrow))]
+        id: K,
+        value: V,
+    }
+
+    let records = HashSet::from([
+        Record {
+            id: "first",
+            value: 1.5f64,
+        },
+        Record {
+            id: "second",
+            value: 2.5f64,
+        },
+    ]);
+    assert_eq!(records.get(&"second").unwrap().value, 2.5f64);
+}
@@ -86,3 +86,136 @@ fn tuple_struct_access() {
     let _r: usize = value.l();
     let _r: &mut usize = value.l_mut();
 }
+
+#[test]
+fn tuple_struct_from_defaults() {
+    #[attrimpl::attrimpl]
+    struct FieldDefault(
+        #[attrimpl(default = 7)] u32,
+        #[attrimpl(from(field_default))] String,
+        Vec<u8>,
+    );
+
+    let value = FieldDefault::from("test".to_string());
+    assert_eq!(value.0, 7);
+    assert_eq!(value.1, "test");
+    assert!(value.2.is_empty());
+
+    #[attrimpl::attrimpl]
+    #[derive(Default)]
+    struct ContainerDefault(#[attrimpl(from(container_default))] String, u32);
+
+    let value = ContainerDefault::from("test".to_string());
+    assert_eq!(value.0, "test");
+    assert_eq!(value.1, 0);
+}
+
+#[test]
+fn tuple_struct_boxed_convert() {
+    #[attrimpl::attrimpl]
+    struct TupleStruct(#[attrimpl(convert(boxed))] Vec<u8>);
+
+    let value = TupleStruct::from(Box::new(vec![1, 2, 3]));
+    assert_eq!(value.0, [1, 2, 3]);
+
+    let value: Box<Vec<u8>> = value.into();
+    assert_eq!(*value, [1, 2, 3]);
+
+    let value = Box::<TupleStruct>::from(value);
+    let value: Vec<u8> = value.into();
+    assert_eq!(value, [1, 2, 3]);
+}
+
+#[test]
+fn tuple_struct_as() {
+    #[attrimpl::attrimpl]
+    struct TupleStruct(#[attrimpl(as)] String);
+
+    let mut value = TupleStruct("test".to_string());
+    value.as_mut().push_str("ing");
+
+    let s: &String = value.as_ref();
+    assert_eq!(s, "testing");
+}
+
+#[test]
+fn tuple_struct_new() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(new(into))]
+    struct TupleStruct(String, #[attrimpl(default = 3)] u32, u64);
+
+    let value = TupleStruct::new("test", 7u32);
+    assert_eq!(value.0, "test");
+    assert_eq!(value.1, 3);
+    assert_eq!(value.2, 7);
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(new)]
+    struct Unit;
+
+    let Unit = Unit::new();
+}
+
+#[test]
+fn tuple_struct_default() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(default)]
+    struct TupleStruct(#[attrimpl(default = 3)] u32, String);
+
+    let value = TupleStruct::default();
+    assert_eq!(value.0, 3);
+    assert_eq!(value.1, "");
+}
+
+#[test]
+fn tuple_struct_display() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(display("{0}:{1}"))]
+    struct Address(String, u16);
+
+    assert_eq!(
+        Address("localhost".to_string(), 80).to_string(),
+        "localhost:80"
+    );
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(display)]
+    struct Meters(#[attrimpl(display(forward))] f64);
+
+    assert_eq!(format!("{:.1}", Meters(2.25)), "2.2");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(display)]
+    struct Wrap<T>(#[attrimpl(display(forward))] T);
+
+    assert_eq!(Wrap("text").to_string(), "text");
+    assert_eq!(format!("{:03}", Wrap(7)), "007");
+}
+
+#[test]
+fn tuple_struct_debug() {
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    struct Token(u64, #[attrimpl(debug(redact))] String);
+
+    let token = Token(1, "secret".to_string());
+    assert_eq!(format!("{:?}", token), r#"Token(1, "***")"#);
+    assert_eq!(token.1, "secret");
+
+    #[attrimpl::attrimpl]
+    #[attrimpl(debug_impl)]
+    struct Unit;
+
+    assert_eq!(format!("{:?}", Unit), "Unit");
+}
+
+#[test]
+fn tuple_struct_key() {
+    #[attrimpl::attrimpl]
+    #[derive(Debug)]
+    struct Tagged(#[attrimpl(key)] u32, &'static str);
+
+    assert_eq!(Tagged(1, "a"), Tagged(1, "b"));
+    assert!(Tagged(1, "b") < Tagged(2, "a"));
+    assert_eq!(Tagged(1, "a").1, "a");
+}
@@ -0,0 +1,37 @@
+#[attrimpl::attrimpl(debgu)]
+struct Typo {
+    #[attrimpl(from)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(debug, debug)]
+struct Duplicate {
+    #[attrimpl(from)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(vis = "pub(crate")]
+struct InvalidVisibility {
+    #[attrimpl(get_ref)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(crate = "::my reexport")]
+struct InvalidCratePath {
+    #[attrimpl(get_ref)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(debug = "true")]
+struct FlagWithValue {
+    #[attrimpl(get_ref)]
+    name: String,
+}
+
+#[attrimpl::attrimpl(vis)]
+struct MissingValue {
+    #[attrimpl(get_ref)]
+    name: String,
+}
+
+fn main() {}
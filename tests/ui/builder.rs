@@ -0,0 +1,18 @@
+#[attrimpl::attrimpl]
+struct NotABuilder {
+    #[attrimpl(builder(into))]
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(builder)]
+struct Builder {
+    #[attrimpl(builder(defualt = 3))]
+    retries: u32,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(builder)]
+struct TupleStruct(String);
+
+fn main() {}
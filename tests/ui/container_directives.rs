@@ -0,0 +1,19 @@
+#[attrimpl::attrimpl]
+#[attrimpl(get_ref(name = "foo"))]
+#[attrimpl(deref)]
+struct NamedStruct {
+    #[attrimpl(skip(get_rf))]
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(get_ref)]
+struct TupleStruct(String);
+
+#[attrimpl::attrimpl]
+#[attrimpl(get_mut)]
+enum Enum {
+    S(String),
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#[attrimpl::attrimpl]
+struct MissingContainer {
+    #[attrimpl(debug(redact))]
+    password: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+struct UnknownParam {
+    #[attrimpl(debug(redacted))]
+    password: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+struct MissingParam {
+    #[attrimpl(debug)]
+    password: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(debug_impl)]
+struct MultipleParams {
+    #[attrimpl(debug(skip, redact))]
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#[attrimpl::attrimpl]
+#[attrimpl(default(variant = "Foo"))]
+struct Struct {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(default)]
+enum MissingVariant {
+    Unit,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(default(variant = "Conected"))]
+enum UnknownVariant {
+    Idle,
+    Connected,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#[attrimpl::attrimpl]
+#[attrimpl(display("{nme} {}"))]
+struct UnknownField {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display("{}"))]
+struct Positional {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display("{0}"))]
+struct Both(#[attrimpl(display(forward))] String);
+
+#[attrimpl::attrimpl]
+#[attrimpl(display(name))]
+struct NotAString {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+enum MissingFormat {
+    Text(String),
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+enum MissingContainer {
+    #[attrimpl(display("quit"))]
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+struct ForwardWithoutContainer(#[attrimpl(display(forward))] String);
+
+#[attrimpl::attrimpl]
+#[attrimpl(display)]
+struct ContainerWithoutForward(String);
+
+#[attrimpl::attrimpl]
+#[attrimpl::display("{0}")]
+struct LegacyDisplay(String);
+
+fn main() {}
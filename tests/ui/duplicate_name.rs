@@ -0,0 +1,7 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(get_ref(name = "foo", name = "bar"))]
+    name: String,
+}
+
+fn main() {}
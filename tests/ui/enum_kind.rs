@@ -0,0 +1,19 @@
+#[attrimpl::attrimpl]
+#[attrimpl(kind(derive(Debug)))]
+enum AlwaysDerived {
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(kind(nme = "Kind"))]
+enum UnknownParam {
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(kind)]
+struct OnStruct {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[attrimpl::attrimpl]
+enum Enum {
+    S(#[attrimpl(as_ref)] String),
+    U8 {
+        #[attrimpl(deref, get_ref)]
+        byte: u8,
+    },
+}
+
+fn main() {}
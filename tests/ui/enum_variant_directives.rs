@@ -0,0 +1,34 @@
+#[attrimpl::attrimpl]
+enum FromWithFields {
+    #[attrimpl(from)]
+    Text(String),
+}
+
+#[attrimpl::attrimpl]
+enum FromWithParams {
+    #[attrimpl(from(boxed))]
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(is)]
+enum UnknownSkip {
+    #[attrimpl(skip(iss))]
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+enum UnsupportedDirective {
+    #[attrimpl(get_ref)]
+    Quit,
+}
+
+#[attrimpl::attrimpl]
+enum MultipleUnitFrom {
+    #[attrimpl(from)]
+    Start,
+    #[attrimpl(from)]
+    Stop,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#[attrimpl::attrimpl]
+#[attrimpl(is(name = "is_it"))]
+enum ContainerName {
+    Text(String),
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(as_variant)]
+enum NameClash {
+    #[attrimpl(is(name = "as_text"))]
+    Text(String),
+}
+
+#[attrimpl::attrimpl]
+enum OnField {
+    Text(#[attrimpl(is)] String),
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(is)]
+struct OnStruct {
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#[attrimpl::attrimpl]
+struct MissingContainer {
+    #[attrimpl(source)]
+    error: std::io::Error,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(error)]
+#[derive(Debug)]
+struct MultipleSources {
+    #[attrimpl(source)]
+    first: std::io::Error,
+    #[attrimpl(source)]
+    second: std::io::Error,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(error)]
+#[derive(Debug)]
+enum AmbiguousSource {
+    Both(
+        #[attrimpl(from(field_default))] std::io::Error,
+        #[attrimpl(from(field_default))] std::fmt::Error,
+    ),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(from)]
+    name: String,
+    value: usize,
+}
+
+#[attrimpl::attrimpl]
+enum Enum {
+    Tuple(#[attrimpl(from)] String, usize),
+    Named {
+        #[attrimpl(from(container_default))]
+        value: u8,
+    },
+}
+
+fn main() {}
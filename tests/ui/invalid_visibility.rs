@@ -0,0 +1,10 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(get_ref(vis = "public"))]
+    name: String,
+
+    #[attrimpl(access(vis = "pub", get_vis = "pub(crate)"))]
+    value: usize,
+}
+
+fn main() {}
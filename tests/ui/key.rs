@@ -0,0 +1,27 @@
+#[attrimpl::attrimpl]
+#[derive(Debug, PartialEq, std::hash::Hash)]
+struct Derived {
+    #[attrimpl(key)]
+    id: u64,
+}
+
+#[attrimpl::attrimpl]
+struct MultipleBorrow {
+    #[attrimpl(key(borrow))]
+    major: u32,
+    #[attrimpl(key)]
+    minor: u32,
+}
+
+#[attrimpl::attrimpl]
+struct UnknownParam {
+    #[attrimpl(key(borow))]
+    id: u64,
+}
+
+#[attrimpl::attrimpl]
+enum OnEnum {
+    Variant(#[attrimpl(key)] u64),
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(set)]
+    a: String,
+    #[attrimpl(get_ref)]
+    set_a: String,
+}
+
+#[attrimpl::attrimpl]
+struct CustomName {
+    #[attrimpl(get_ref)]
+    a: String,
+    #[attrimpl(get_ref(name = "a"))]
+    b: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(is)]
+enum Enum {
+    First,
+    #[attrimpl(is(name = "is_first"))]
+    Second,
+}
+
+fn main() {}
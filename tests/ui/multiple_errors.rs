@@ -0,0 +1,15 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(get_reff, deref_mt, from(boxd))]
+    name: String,
+
+    #[attrimpl(as_ref, as_ref)]
+    value: usize,
+}
+
+// the struct is still emitted, so using it only reports the errors above
+fn use_struct(value: NamedStruct) -> usize {
+    value.value
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(from, convert)]
+    #[attrimpl(as_ref, as)]
+    #[attrimpl(get_ref(name = "foo"), access(name = "foo"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[attrimpl::attrimpl]
+#[attrimpl(new, new(into))]
+struct Duplicated {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(new(int))]
+struct UnknownParameter {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+struct FieldLevel {
+    #[attrimpl(new)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(set, get_ref(name = "set_name"))]
+    name: String,
+
+    #[attrimpl(access(set), set)]
+    value: usize,
+
+    #[attrimpl(set(chain, previous))]
+    id: u64,
+
+    #[attrimpl(set(int))]
+    hobby: String,
+}
+
+fn main() {}
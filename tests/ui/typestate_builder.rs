@@ -0,0 +1,16 @@
+#[attrimpl::attrimpl]
+#[attrimpl(builder(typestate, typestate))]
+struct Duplicated {
+    name: String,
+}
+
+#[attrimpl::attrimpl]
+#[attrimpl(builder(typestate))]
+struct Config {
+    name: String,
+    value: usize,
+}
+
+fn main() {
+    let _ = Config::builder().name("test".to_string()).build();
+}
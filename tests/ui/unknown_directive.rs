@@ -0,0 +1,10 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(get_refs)]
+    name: String,
+
+    #[attrimpl(frobnicate)]
+    value: usize,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[attrimpl::attrimpl]
+struct NamedStruct {
+    #[attrimpl(get_ref(nme = "foo"))]
+    name: String,
+
+    #[attrimpl(access(get_cpy))]
+    value: usize,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[attrimpl::attrimpl]
+struct Config {
+    #[attrimpl(with, get_ref(name = "with_name"))]
+    name: String,
+
+    #[attrimpl(with(chain))]
+    value: usize,
+}
+
+fn main() {}